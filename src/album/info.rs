@@ -0,0 +1,130 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    album::Endpoints,
+    error::{Error, LastFMError},
+    model::{
+        custom_deserialization::{one_or_many, tag_list},
+        Artist, Image, Tag, Wiki,
+    },
+    utilities::{deserialize_option_u64, deserialize_u64},
+    Client, RequestBuilder,
+};
+
+/// The main album info structure.
+///
+/// Contains detailed information about an album, such as its tracklist, listener count and
+/// wiki. When the request is made for a given user, the user's play count of the album is
+/// available as well.
+#[derive(Debug, Deserialize)]
+pub struct AlbumInfo {
+    /// The name of the album.
+    pub name: String,
+    /// The name of the album's artist.
+    pub artist: String,
+    /// The MusicBrainz ID of the album.
+    pub mbid: Option<String>,
+    /// The Last.fm URL of the album.
+    pub url: String,
+    /// The album's cover art, in multiple sizes.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+    /// The amount of unique listeners of the album.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub listeners: u64,
+    /// The amount of times the album has been played across Last.fm.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub playcount: u64,
+    /// The amount of times the given user has played the album.
+    #[serde(rename = "userplaycount", default, deserialize_with = "deserialize_option_u64")]
+    pub user_playcount: Option<u64>,
+    /// The tracklist of the album. [None] if unknown to Last.fm.
+    pub tracks: Option<AlbumTracks>,
+    /// The most popular tags applied to the album.
+    #[serde(default, deserialize_with = "tag_list")]
+    pub tags: Vec<Tag>,
+    /// The wiki of the album, if it has one.
+    pub wiki: Option<Wiki>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumTracks {
+    /// A [Vec] containing the tracks of the album, in order.
+    #[serde(rename = "track", default, deserialize_with = "one_or_many")]
+    pub tracks: Vec<AlbumTrack>,
+}
+
+/// A track appearing on an album.
+#[derive(Debug, Deserialize)]
+pub struct AlbumTrack {
+    /// The name of the track.
+    pub name: String,
+    /// The Last.fm URL of the track.
+    pub url: String,
+    /// The length of the track, in seconds. [None] if unknown to Last.fm.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub duration: Option<u64>,
+    /// The artist who performed the track.
+    pub artist: Artist,
+    /// Attributes associated with the track.
+    #[serde(rename = "@attr")]
+    pub attrs: Option<AlbumTrackAttributes>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumTrackAttributes {
+    /// The position of the track on the album.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub rank: Option<u64>,
+}
+
+impl AlbumInfo {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, AlbumInfo> {
+        let url = client.build_url(vec![("method", "album.getInfo"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, album: &str) -> RequestBuilder<'a, AlbumInfo> {
+        let url = client.build_url(vec![("method", "album.getInfo"), ("artist", artist), ("album", album)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, AlbumInfo> {
+    add_param!(with_username, username, &str);
+    add_param!(with_lang, lang, &str);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<AlbumInfo, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(albums) => Ok(albums.album.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn album_info_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, AlbumInfo> {
+        AlbumInfo::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves detailed information about the given album, including its tracklist. The wiki
+    /// is returned in English, unless another language is given through the `with_lang`
+    /// parameter function, using its ISO 639 alpha-2 code.
+    ///
+    /// * `artist` - The name of the album's artist.
+    /// * `album` - The name of the album.
+    pub async fn album_info(&'a mut self, artist: &str, album: &str) -> RequestBuilder<'a, AlbumInfo> {
+        AlbumInfo::build(self, artist, album).await
+    }
+}
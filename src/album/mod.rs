@@ -0,0 +1,20 @@
+//! Last.fm Album API Endpoints
+//!
+//! Contains structs and methods related to working with the album-related endpoints
+//! available through the Last.fm API
+
+use serde::Deserialize;
+
+pub mod info;
+pub mod search;
+pub mod tags;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    pub album: Option<info::AlbumInfo>,
+    #[serde(rename = "results")]
+    pub search: Option<search::AlbumSearch>,
+    pub tags: Option<tags::Tags>,
+    #[serde(rename = "toptags")]
+    pub top_tags: Option<tags::TopTags>,
+}
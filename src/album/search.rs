@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    album::Endpoints,
+    error::{Error, LastFMError},
+    model::{Image, OpenSearch},
+    Client, RequestBuilder,
+};
+
+/// The main album search structure.
+///
+/// This is splitted off into two areas: One, the OpenSearch metadata (used for paginating
+/// through the results), and two, the albums matching the search.
+///
+/// For details on the metadata available, refer to [OpenSearch]. For details on the album
+/// information available, refer to [AlbumMatch].
+#[derive(Debug, Deserialize)]
+pub struct AlbumSearch {
+    /// The OpenSearch metadata of the search.
+    #[serde(flatten)]
+    pub opensearch: OpenSearch,
+    /// The albums matching the search.
+    #[serde(rename = "albummatches")]
+    pub matches: AlbumMatches,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumMatches {
+    /// A [Vec] containing the albums matching the search, best matches first.
+    #[serde(rename = "album")]
+    pub albums: Vec<AlbumMatch>,
+}
+
+/// An album matching a search.
+#[derive(Debug, Deserialize)]
+pub struct AlbumMatch {
+    /// The name of the album.
+    pub name: String,
+    /// The name of the album's artist.
+    pub artist: String,
+    /// The Last.fm URL of the album.
+    pub url: String,
+    /// The MusicBrainz ID of the album.
+    pub mbid: Option<String>,
+    /// The album's cover art, in multiple sizes.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+}
+
+impl AlbumSearch {
+    pub async fn build<'a>(client: &'a mut Client, album: &str) -> RequestBuilder<'a, AlbumSearch> {
+        let url = client.build_url(vec![("method", "album.search"), ("album", album)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, AlbumSearch> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<AlbumSearch, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(albums) => Ok(albums.search.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Searches for albums by name.
+    ///
+    /// * `album` - The name of the album to search for.
+    pub async fn search_albums(&'a mut self, album: &str) -> RequestBuilder<'a, AlbumSearch> {
+        AlbumSearch::build(self, album).await
+    }
+}
@@ -0,0 +1,150 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    album::Endpoints,
+    error::{Error, LastFMError},
+    model::{custom_deserialization::one_or_many, Tag, MAX_TAGS},
+    Client, RequestBuilder,
+};
+
+/// The main tags structure.
+///
+/// Contains the tags a user applied to the given album. For details on the tag information
+/// available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct Tags {
+    /// A [Vec] containing the tags the user applied to the album.
+    #[serde(rename = "tag", default, deserialize_with = "one_or_many")]
+    pub tags: Vec<Tag>,
+    /// The album the tags were applied to.
+    #[serde(rename = "@attr")]
+    pub attrs: TagsAttributes,
+}
+
+/// The main top tags structure.
+///
+/// Contains the tags applied most to the given album across Last.fm, alongside their counts. For
+/// details on the tag information available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct TopTags {
+    /// A [Vec] containing the tags applied most to the album, most applied first.
+    #[serde(rename = "tag", default, deserialize_with = "one_or_many")]
+    pub tags: Vec<Tag>,
+    /// The album the tags were applied to.
+    #[serde(rename = "@attr")]
+    pub attrs: TagsAttributes,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagsAttributes {
+    /// The name of the album's artist.
+    pub artist: String,
+    /// The name of the album.
+    pub album: String,
+}
+
+/// Applies tags to an album on behalf of the authenticated user. Up to [MAX_TAGS] tags can be applied
+/// at once.
+///
+/// Last.fm doesn't return any data for this method, so sending the request only reports
+/// whether or not it succeeded.
+#[derive(Debug)]
+pub struct AddTags;
+
+/// Removes a tag the authenticated user applied to an album.
+#[derive(Debug)]
+pub struct RemoveTag;
+
+impl Tags {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, Tags> {
+        let url = client.build_url(vec![("method", "album.getTags"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, album: &str) -> RequestBuilder<'a, Tags> {
+        let url = client.build_url(vec![("method", "album.getTags"), ("artist", artist), ("album", album)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl TopTags {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "album.getTopTags"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, album: &str) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "album.getTopTags"), ("artist", artist), ("album", album)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl AddTags {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, album: &str, tags: &[&str]) -> Result<RequestBuilder<'a, AddTags>, Error> {
+        if tags.is_empty() {
+            return Err(Error::NoTags);
+        }
+        if tags.len() > MAX_TAGS {
+            return Err(Error::TooManyTags(tags.len()));
+        }
+
+        let tags = tags.join(",");
+        let url = client.build_url(vec![("method", "album.addTags"), ("artist", artist), ("album", album), ("tags", &tags)]).await;
+        Ok(RequestBuilder { client, url, phantom: PhantomData })
+    }
+}
+
+impl RemoveTag {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, album: &str, tag: &str) -> RequestBuilder<'a, RemoveTag> {
+        let url = client.build_url(vec![("method", "album.removeTag"), ("artist", artist), ("album", album), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+tag_requests!();
+
+impl<'a> Client {
+    pub async fn album_tags_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, Tags> {
+        Tags::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves the tags a user applied to the given album.
+    ///
+    /// * `artist` - The name of the album's artist.
+    /// * `album` - The name of the album.
+    pub async fn album_tags(&'a mut self, artist: &str, album: &str) -> RequestBuilder<'a, Tags> {
+        Tags::build(self, artist, album).await
+    }
+
+    pub async fn album_top_tags_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, TopTags> {
+        TopTags::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves the tags applied most to the given album across Last.fm.
+    ///
+    /// * `artist` - The name of the album's artist.
+    /// * `album` - The name of the album.
+    pub async fn album_top_tags(&'a mut self, artist: &str, album: &str) -> RequestBuilder<'a, TopTags> {
+        TopTags::build(self, artist, album).await
+    }
+
+    /// Applies the given tags to the album on behalf of the authenticated user. Fails with
+    /// [Error::NoTags] if no tags are given, or [Error::TooManyTags] if more than [MAX_TAGS] are given.
+    ///
+    /// * `artist` - The name of the album's artist.
+    /// * `album` - The name of the album.
+    /// * `tags` - The tags to apply.
+    pub async fn add_album_tags(&'a mut self, artist: &str, album: &str, tags: &[&str]) -> Result<RequestBuilder<'a, AddTags>, Error> {
+        AddTags::build(self, artist, album, tags).await
+    }
+
+    /// Removes a tag the authenticated user applied to the album.
+    ///
+    /// * `artist` - The name of the album's artist.
+    /// * `album` - The name of the album.
+    /// * `tag` - The tag to remove.
+    pub async fn remove_album_tag(&'a mut self, artist: &str, album: &str, tag: &str) -> RequestBuilder<'a, RemoveTag> {
+        RemoveTag::build(self, artist, album, tag).await
+    }
+}
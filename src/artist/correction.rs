@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::Artist,
+    Client, RequestBuilder,
+};
+
+/// The main artist correction structure.
+///
+/// Contains the canonical artist Last.fm would correct a submission of the given artist to.
+/// For details on the artist information available, refer to [Artist].
+#[derive(Debug, Deserialize)]
+pub struct ArtistCorrection {
+    /// The canonical artist.
+    pub artist: Artist,
+}
+
+impl ArtistCorrection {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, ArtistCorrection> {
+        let url = client.build_url(vec![("method", "artist.getCorrection"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, ArtistCorrection> {
+    /// Sends the request, returning [None] if Last.fm doesn't know of the given artist.
+    pub async fn send(&'a mut self) -> Result<Option<ArtistCorrection>, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.correction),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Looks up the canonical artist Last.fm would correct the given artist to.
+    ///
+    /// * `artist` - The name of the artist.
+    pub async fn artist_correction(&'a mut self, artist: &str) -> RequestBuilder<'a, ArtistCorrection> {
+        ArtistCorrection::build(self, artist).await
+    }
+}
@@ -0,0 +1,110 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::{custom_deserialization::tag_list, Artist, Image, Tag, Wiki},
+    utilities::{deserialize_bool, deserialize_option_u64, deserialize_u64},
+    Client, RequestBuilder,
+};
+
+/// The main artist info structure.
+///
+/// Contains detailed information about an artist, such as their listener count, biography and
+/// similar artists. When the request is made for a given user, the user's play count of the
+/// artist is available as well.
+#[derive(Debug, Deserialize)]
+pub struct ArtistInfo {
+    /// The name of the artist.
+    pub name: String,
+    /// The MusicBrainz ID of the artist.
+    pub mbid: Option<String>,
+    /// The Last.fm URL of the artist.
+    pub url: String,
+    /// The main images linked to the artist.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+    /// Whether or not the artist is currently on tour.
+    #[serde(rename = "ontour", deserialize_with = "deserialize_bool")]
+    pub on_tour: bool,
+    /// Listening statistics of the artist.
+    pub stats: ArtistStats,
+    /// Artists similar to the artist.
+    pub similar: SimilarArtists,
+    /// The most popular tags applied to the artist.
+    #[serde(default, deserialize_with = "tag_list")]
+    pub tags: Vec<Tag>,
+    /// The biography of the artist, in the requested language.
+    pub bio: Option<Wiki>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtistStats {
+    /// The amount of unique listeners of the artist.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub listeners: u64,
+    /// The amount of times the artist has been played across Last.fm.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub playcount: u64,
+    /// The amount of times the given user has played the artist.
+    #[serde(rename = "userplaycount", default, deserialize_with = "deserialize_option_u64")]
+    pub user_playcount: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimilarArtists {
+    /// A [Vec] containing the artists similar to the artist. For details on the artist
+    /// information available, refer to [Artist].
+    #[serde(rename = "artist", default)]
+    pub artists: Vec<Artist>,
+}
+
+impl ArtistInfo {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, ArtistInfo> {
+        let url = client.build_url(vec![("method", "artist.getInfo"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, ArtistInfo> {
+        let url = client.build_url(vec![("method", "artist.getInfo"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, ArtistInfo> {
+    add_param!(with_username, username, &str);
+    add_param!(with_lang, lang, &str);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<ArtistInfo, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.artist.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn artist_info_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, ArtistInfo> {
+        ArtistInfo::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves detailed information about the given artist. The biography is returned in
+    /// English, unless another language is given through the `with_lang` parameter function,
+    /// using its ISO 639 alpha-2 code.
+    ///
+    /// * `artist` - The name of the artist.
+    pub async fn artist_info(&'a mut self, artist: &str) -> RequestBuilder<'a, ArtistInfo> {
+        ArtistInfo::build(self, artist).await
+    }
+}
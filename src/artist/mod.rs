@@ -0,0 +1,33 @@
+//! Last.fm Artist API Endpoints
+//!
+//! Contains structs and methods related to working with the artist-related endpoints
+//! available through the Last.fm API
+
+use crate::model::custom_deserialization::correction as deserialize_correction;
+use serde::Deserialize;
+
+pub mod correction;
+pub mod info;
+pub mod search;
+pub mod similar;
+pub mod tags;
+pub mod top_albums;
+pub mod top_tracks;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    pub artist: Option<info::ArtistInfo>,
+    #[serde(rename = "results")]
+    pub search: Option<search::ArtistSearch>,
+    #[serde(rename = "similarartists")]
+    pub similar_artists: Option<similar::Similar>,
+    #[serde(rename = "toptracks")]
+    pub top_tracks: Option<top_tracks::TopTracks>,
+    #[serde(rename = "topalbums")]
+    pub top_albums: Option<top_albums::TopAlbums>,
+    pub tags: Option<tags::Tags>,
+    #[serde(rename = "toptags")]
+    pub top_tags: Option<tags::TopTags>,
+    #[serde(rename = "corrections", default, deserialize_with = "deserialize_correction")]
+    pub correction: Option<correction::ArtistCorrection>,
+}
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::{Image, OpenSearch},
+    utilities::deserialize_u64,
+    Client, RequestBuilder,
+};
+
+/// The main artist search structure.
+///
+/// This is splitted off into two areas: One, the OpenSearch metadata (used for paginating
+/// through the results), and two, the artists matching the search.
+///
+/// For details on the metadata available, refer to [OpenSearch]. For details on the artist
+/// information available, refer to [ArtistMatch].
+#[derive(Debug, Deserialize)]
+pub struct ArtistSearch {
+    /// The OpenSearch metadata of the search.
+    #[serde(flatten)]
+    pub opensearch: OpenSearch,
+    /// The artists matching the search.
+    #[serde(rename = "artistmatches")]
+    pub matches: ArtistMatches,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtistMatches {
+    /// A [Vec] containing the artists matching the search, best matches first.
+    #[serde(rename = "artist")]
+    pub artists: Vec<ArtistMatch>,
+}
+
+/// An artist matching a search.
+#[derive(Debug, Deserialize)]
+pub struct ArtistMatch {
+    /// The name of the artist.
+    pub name: String,
+    /// The Last.fm URL of the artist.
+    pub url: String,
+    /// The amount of unique listeners of the artist.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub listeners: u64,
+    /// The MusicBrainz ID of the artist.
+    pub mbid: Option<String>,
+    /// The main images linked to the artist.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+}
+
+impl ArtistSearch {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, ArtistSearch> {
+        let url = client.build_url(vec![("method", "artist.search"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, ArtistSearch> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<ArtistSearch, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.search.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Searches for artists by name.
+    ///
+    /// * `artist` - The name of the artist to search for.
+    pub async fn search_artists(&'a mut self, artist: &str) -> RequestBuilder<'a, ArtistSearch> {
+        ArtistSearch::build(self, artist).await
+    }
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::Artist,
+    Client, RequestBuilder,
+};
+
+/// The main similar structure.
+///
+/// This structure only has one component to it: the artists that are similar to the
+/// one provided. Each artist carries its `match`, describing how similar it is to the
+/// given artist. For details on the information available for the artists, refer to the
+/// [Artist] struct.
+#[derive(Debug, Deserialize)]
+pub struct Similar {
+    /// A [Vec] containing similar [Artist]s.
+    #[serde(rename = "artist")]
+    pub artists: Vec<Artist>,
+}
+
+impl Similar {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, Similar> {
+        let url = client.build_url(vec![("method", "artist.getSimilar"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, Similar> {
+        let url = client.build_url(vec![("method", "artist.getSimilar"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, Similar> {
+    add_param!(with_limit, limit, usize);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<Similar, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.similar_artists.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn similar_artists_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, Similar> {
+        Similar::build_by_mbid(self, mbid).await
+    }
+
+    pub async fn similar_artists(&'a mut self, artist: &str) -> RequestBuilder<'a, Similar> {
+        Similar::build(self, artist).await
+    }
+}
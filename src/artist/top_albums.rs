@@ -0,0 +1,71 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::{Album, Attributes},
+    Client, RequestBuilder,
+};
+
+/// The main top albums structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the artist and pagination attributes), and two,
+/// a list of the artist's most popular albums.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the album information available, refer to [Album].
+#[derive(Debug, Deserialize)]
+pub struct TopAlbums {
+    /// The attributes associated with the artist's Top Albums listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the artist's most popular albums.
+    #[serde(rename = "album")]
+    pub albums: Vec<Album>,
+}
+
+impl TopAlbums {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, TopAlbums> {
+        let url = client.build_url(vec![("method", "artist.getTopAlbums"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, TopAlbums> {
+        let url = client.build_url(vec![("method", "artist.getTopAlbums"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopAlbums> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<TopAlbums, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.top_albums.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn artist_top_albums_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, TopAlbums> {
+        TopAlbums::build_by_mbid(self, mbid).await
+    }
+
+    pub async fn artist_top_albums(&'a mut self, artist: &str) -> RequestBuilder<'a, TopAlbums> {
+        TopAlbums::build(self, artist).await
+    }
+}
@@ -0,0 +1,71 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    artist::Endpoints,
+    error::{Error, LastFMError},
+    model::{Attributes, Track},
+    Client, RequestBuilder,
+};
+
+/// The main top tracks structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the artist and pagination attributes), and two,
+/// a list of the artist's most popular tracks.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the track information available, refer to [Track].
+#[derive(Debug, Deserialize)]
+pub struct TopTracks {
+    /// The attributes associated with the artist's Top Tracks listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the artist's most popular tracks.
+    #[serde(rename = "track")]
+    pub tracks: Vec<Track>,
+}
+
+impl TopTracks {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, TopTracks> {
+        let url = client.build_url(vec![("method", "artist.getTopTracks"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str) -> RequestBuilder<'a, TopTracks> {
+        let url = client.build_url(vec![("method", "artist.getTopTracks"), ("artist", artist)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTracks> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<TopTracks, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(artists) => Ok(artists.top_tracks.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn artist_top_tracks_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, TopTracks> {
+        TopTracks::build_by_mbid(self, mbid).await
+    }
+
+    pub async fn artist_top_tracks(&'a mut self, artist: &str) -> RequestBuilder<'a, TopTracks> {
+        TopTracks::build(self, artist).await
+    }
+}
@@ -0,0 +1,40 @@
+//! Last.fm Authentication API Endpoints
+//!
+//! Contains structs and methods related to authenticating users with the Last.fm API. A successful
+//! authentication results in a [Session], which can be attached to a [Client] so that any signed
+//! requests made afterwards are made on behalf of the authenticated user.
+//!
+//! The desktop authentication flow works as follows:
+//!
+//! 1. Fetch a request token with [Client::auth_token].
+//! 2. Send the user to the URL returned by [Client::auth_url] so they can approve the application.
+//! 3. Exchange the approved token for a [Session] with [Client::auth_session].
+//!
+//! The web authentication flow works similarly, except that no request token has to be fetched:
+//!
+//! 1. Redirect the user to the URL returned by [Client::web_auth_url].
+//! 2. Once approved, Last.fm redirects the user to the given callback URL, which carries the token.
+//! 3. Exchange the callback URL for a [Session] with [Client::session_from_callback].
+//!
+//! Applications that can't open a browser can authenticate with a username and password directly
+//! through [Client::mobile_session].
+//!
+//! [Session]: crate::auth::session::Session
+//! [Client]: crate::Client
+//! [Client::auth_token]: crate::Client::auth_token
+//! [Client::auth_url]: crate::Client::auth_url
+//! [Client::auth_session]: crate::Client::auth_session
+//! [Client::web_auth_url]: crate::Client::web_auth_url
+//! [Client::session_from_callback]: crate::Client::session_from_callback
+//! [Client::mobile_session]: crate::Client::mobile_session
+
+use serde::Deserialize;
+
+pub mod session;
+pub mod token;
+pub mod web;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    pub session: Option<session::Session>,
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use crate::{
+    auth::Endpoints,
+    error::{Error, LastFMError},
+    utilities::deserialize_bool,
+    Client, RequestBuilder,
+};
+
+/// An authenticated Last.fm session.
+///
+/// Session keys don't expire by default, meaning a session can be reused until the user revokes
+/// the application's access from their Last.fm settings. To avoid authenticating the user each time
+/// the application is started, sessions can be saved to and loaded from a file.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Session {
+    /// The name of the authenticated user.
+    pub name: String,
+    /// The session key, sent as the `sk` parameter of signed requests.
+    pub key: String,
+    /// Whether or not the authenticated user is a Last.fm Pro subscriber.
+    #[serde(deserialize_with = "deserialize_bool")]
+    pub subscriber: bool,
+}
+
+impl Session {
+    pub async fn build<'a>(client: &'a mut Client, token: &str) -> RequestBuilder<'a, Session> {
+        let url = client.build_url(vec![("method", "auth.getSession"), ("token", token)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build_mobile<'a>(client: &'a mut Client, username: &str, password: &str) -> RequestBuilder<'a, Session> {
+        let url = client.build_url(vec![("method", "auth.getMobileSession"), ("username", username), ("password", password)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    /// Saves the session to the given file as JSON, replacing the file if it already exists. As the
+    /// session key grants access to the user's account, the file is only readable and writable by
+    /// its owner on Unix platforms.
+    ///
+    /// * `path` - The path of the file to save the session to.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let json = serde_json::to_string(self).map_err(Error::ParsingError)?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path).map_err(Error::IOError)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            // The mode is only applied when the file is created, so tighten up pre-existing files too.
+            file.set_permissions(std::fs::Permissions::from_mode(0o600)).map_err(Error::IOError)?;
+        }
+        file.write_all(json.as_bytes()).map_err(Error::IOError)
+    }
+
+    /// Loads a session previously saved with [Session::save] from the given file.
+    ///
+    /// * `path` - The path of the file to load the session from.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Session, Error> {
+        let file = File::open(path).map_err(Error::IOError)?;
+        serde_json::from_reader(file).map_err(Error::ParsingError)
+    }
+}
+
+impl<'a> RequestBuilder<'a, Session> {
+    pub async fn send(&'a mut self) -> Result<Session, Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                Ok(auth) => Ok(auth.session.unwrap()),
+                Err(e) => Err(Error::ParsingError(e)),
+            },
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Exchanges an approved request token for a [Session].
+    ///
+    /// * `token` - A request token the user has approved.
+    pub async fn auth_session(&'a mut self, token: &str) -> RequestBuilder<'a, Session> {
+        Session::build(self, token).await
+    }
+
+    /// Authenticates a user with their username and password, without requiring any interaction
+    /// with a browser. The credentials are only ever sent in the body of a signed POST request, over
+    /// HTTPS as required by Last.fm.
+    ///
+    /// If the credentials are incorrect, sending the request results in an [`AuthenticationFailed`] error.
+    ///
+    /// * `username` - The username or email address of the user.
+    /// * `password` - The password of the user.
+    ///
+    /// [`AuthenticationFailed`]: crate::error::LastFMErrorResponse::AuthenticationFailed
+    pub async fn mobile_session(&'a mut self, username: &str, password: &str) -> RequestBuilder<'a, Session> {
+        Session::build_mobile(self, username, password).await
+    }
+}
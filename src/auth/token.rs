@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+use url::Url;
+
+use crate::{
+    error::{Error, LastFMError},
+    Client, RequestBuilder, AUTH_ENDPOINT,
+};
+
+/// An unauthorized request token.
+///
+/// Tokens are valid for 60 minutes from the moment they are granted, and can only be exchanged
+/// for a session once the user has approved them through the URL given by [Client::auth_url].
+#[derive(Debug, Deserialize)]
+pub struct Token {
+    /// The request token.
+    pub token: String,
+}
+
+impl Token {
+    pub async fn build<'a>(client: &'a mut Client) -> RequestBuilder<'a, Token> {
+        let url = client.build_url(vec![("method", "auth.getToken")]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, Token> {
+    pub async fn send(&'a mut self) -> Result<Token, Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => match serde_json::from_str::<Token>(&body) {
+                Ok(token) => Ok(token),
+                Err(e) => Err(Error::ParsingError(e)),
+            },
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn auth_token(&'a mut self) -> RequestBuilder<'a, Token> {
+        Token::build(self).await
+    }
+
+    /// Builds the URL the user has to visit in order to approve the given request token.
+    ///
+    /// * `token` - The request token retrieved through [Client::auth_token].
+    pub fn auth_url(&self, token: &str) -> Url {
+        let mut url = Url::parse(AUTH_ENDPOINT).unwrap();
+        url.query_pairs_mut().append_pair("api_key", &self.api_key).append_pair("token", token);
+        url
+    }
+}
@@ -0,0 +1,40 @@
+use url::Url;
+
+use crate::{auth::session::Session, error::Error, Client, AUTH_ENDPOINT};
+
+/// Extracts the request token from the URL Last.fm redirected the user to after they approved
+/// the application. Both absolute URLs and request targets (e.g. `/callback?token=...`) are accepted.
+///
+/// Returns [None] if the URL couldn't be parsed, or doesn't contain a `token` parameter.
+///
+/// * `callback` - The callback URL, including its query string.
+pub fn callback_token(callback: &str) -> Option<String> {
+    let url = match Url::parse(callback) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => Url::parse("http://localhost/").ok()?.join(callback).ok()?,
+        Err(_) => return None,
+    };
+
+    url.query_pairs().find(|(key, value)| key == "token" && !value.is_empty()).map(|(_, value)| value.into_owned())
+}
+
+impl Client {
+    /// Builds the URL the user has to visit in order to approve the application in the web
+    /// authentication flow. Once approved, Last.fm redirects the user to the given callback URL with
+    /// a `token` parameter added to it.
+    ///
+    /// * `callback` - The URL Last.fm should redirect the user to.
+    pub fn web_auth_url(&self, callback: &str) -> Url {
+        let mut url = Url::parse(AUTH_ENDPOINT).unwrap();
+        url.query_pairs_mut().append_pair("api_key", &self.api_key).append_pair("cb", callback);
+        url
+    }
+
+    /// Exchanges the token contained in the given callback URL for a [Session].
+    ///
+    /// * `callback` - The URL Last.fm redirected the user to, including its query string.
+    pub async fn session_from_callback(&mut self, callback: &str) -> Result<Session, Error> {
+        let token = callback_token(callback).ok_or_else(|| Error::InvalidCallback(callback.to_owned()))?;
+        self.auth_session(&token).await.send().await
+    }
+}
@@ -0,0 +1,20 @@
+//! Last.fm Chart API Endpoints
+//!
+//! Contains structs and methods related to working with the global Last.fm charts
+//! available through the Last.fm API
+
+use serde::Deserialize;
+
+pub mod top_artists;
+pub mod top_tags;
+pub mod top_tracks;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    #[serde(rename = "artists")]
+    pub top_artists: Option<top_artists::TopArtists>,
+    #[serde(rename = "tracks")]
+    pub top_tracks: Option<top_tracks::TopTracks>,
+    #[serde(rename = "tags")]
+    pub top_tags: Option<top_tags::TopTags>,
+}
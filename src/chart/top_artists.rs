@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    chart::Endpoints,
+    error::{Error, LastFMError},
+    model::{Artist, Attributes},
+    Client, RequestBuilder,
+};
+
+/// The main top artists structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for paginating through the chart), and two, a list of the most
+/// popular artists across Last.fm, along with their listener and play counts.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the artist information available, refer to [Artist].
+#[derive(Debug, Deserialize)]
+pub struct TopArtists {
+    /// The attributes associated with the Top Artists listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular artists across Last.fm.
+    #[serde(rename = "artist")]
+    pub artists: Vec<Artist>,
+}
+
+impl TopArtists {
+    pub async fn build<'a>(client: &'a mut Client) -> RequestBuilder<'a, TopArtists> {
+        let url = client.build_url(vec![("method", "chart.getTopArtists")]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopArtists> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopArtists, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(charts) => Ok(charts.top_artists.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn chart_top_artists(&'a mut self) -> RequestBuilder<'a, TopArtists> {
+        TopArtists::build(self).await
+    }
+}
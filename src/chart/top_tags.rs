@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    chart::Endpoints,
+    error::{Error, LastFMError},
+    model::{Attributes, Tag},
+    Client, RequestBuilder,
+};
+
+/// The main top tags structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for paginating through the chart), and two, a list of the most
+/// applied tags across Last.fm, along with their reach and taggings.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the tag information available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct TopTags {
+    /// The attributes associated with the Top Tags listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most applied tags across Last.fm.
+    #[serde(rename = "tag")]
+    pub tags: Vec<Tag>,
+}
+
+impl TopTags {
+    pub async fn build<'a>(client: &'a mut Client) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "chart.getTopTags")]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTags> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopTags, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(charts) => Ok(charts.top_tags.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn chart_top_tags(&'a mut self) -> RequestBuilder<'a, TopTags> {
+        TopTags::build(self).await
+    }
+}
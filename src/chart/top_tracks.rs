@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    chart::Endpoints,
+    error::{Error, LastFMError},
+    model::{Attributes, Track},
+    Client, RequestBuilder,
+};
+
+/// The main top tracks structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for paginating through the chart), and two, a list of the most
+/// popular tracks across Last.fm, along with their listener and play counts.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the track information available, refer to [Track].
+#[derive(Debug, Deserialize)]
+pub struct TopTracks {
+    /// The attributes associated with the Top Tracks listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular tracks across Last.fm.
+    #[serde(rename = "track")]
+    pub tracks: Vec<Track>,
+}
+
+impl TopTracks {
+    pub async fn build<'a>(client: &'a mut Client) -> RequestBuilder<'a, TopTracks> {
+        let url = client.build_url(vec![("method", "chart.getTopTracks")]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTracks> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopTracks, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(charts) => Ok(charts.top_tracks.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn chart_top_tracks(&'a mut self) -> RequestBuilder<'a, TopTracks> {
+        TopTracks::build(self).await
+    }
+}
@@ -8,6 +8,8 @@
 //!
 //! [LastFMErrorResponse]: crate::error::LastFMErrorResponse
 
+use crate::model::MAX_TAGS;
+use crate::track::scrobble::MAX_SCROBBLES;
 use serde::Deserialize;
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -19,8 +21,39 @@ pub enum Error {
     ParsingError(serde_json::error::Error),
     /// An error occurred while a request was being made to the API.
     HTTPError(reqwest::Error),
+    /// An error occurred while reading or writing a file.
+    IOError(std::io::Error),
     /// An error returned by the Last.fm API.
     LastFMError(LastFMErrorResponse),
+    /// The request has to be signed, but the client was not given an API shared secret.
+    MissingSecret,
+    /// The given authentication callback URL couldn't be parsed, or is missing the `token` parameter.
+    InvalidCallback(String),
+    /// More scrobbles than the Last.fm API accepts in a single request were submitted at once.
+    TooManyScrobbles(usize),
+    /// An empty batch of scrobbles was submitted.
+    NoScrobbles,
+    /// More tags than the Last.fm API accepts in a single request were applied at once.
+    TooManyTags(usize),
+    /// An empty list of tags was applied.
+    NoTags,
+    /// The given country isn't listed by ISO 3166-1, and can't be passed to the geographic endpoints.
+    UnknownCountry(String),
+}
+
+impl Error {
+    /// Returns whether or not the error is likely to be temporary, meaning the same request may
+    /// succeed if it is retried later, e.g. when the device is offline or Last.fm is having issues.
+    pub fn is_transient(&self) -> bool {
+        match *self {
+            Error::HTTPError(_) => true,
+            Error::LastFMError(ref inner) => matches!(
+                inner,
+                LastFMErrorResponse::OperationFailed(_) | LastFMErrorResponse::ServiceOffline(_) | LastFMErrorResponse::RateLimitExceeded(_)
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl StdError for Error {
@@ -28,7 +61,15 @@ impl StdError for Error {
         match *self {
             Error::ParsingError(ref e) => Some(e),
             Error::HTTPError(ref e) => Some(e),
+            Error::IOError(ref e) => Some(e),
             Error::LastFMError(_) => None,
+            Error::MissingSecret => None,
+            Error::InvalidCallback(_) => None,
+            Error::TooManyScrobbles(_) => None,
+            Error::NoScrobbles => None,
+            Error::TooManyTags(_) => None,
+            Error::NoTags => None,
+            Error::UnknownCountry(_) => None,
         }
     }
 }
@@ -38,7 +79,15 @@ impl Display for Error {
         match *self {
             Error::ParsingError(ref inner) => inner.fmt(f),
             Error::HTTPError(ref inner) => inner.fmt(f),
+            Error::IOError(ref inner) => inner.fmt(f),
             Error::LastFMError(ref inner) => inner.fmt(f),
+            Error::MissingSecret => write!(f, "An API shared secret is required to sign this request"),
+            Error::InvalidCallback(ref url) => write!(f, "No authentication token found in callback URL {}", url),
+            Error::TooManyScrobbles(count) => write!(f, "{} scrobbles submitted at once, at most {} are allowed per request", count, MAX_SCROBBLES),
+            Error::NoScrobbles => write!(f, "No scrobbles submitted, at least one is required per request"),
+            Error::TooManyTags(count) => write!(f, "{} tags applied at once, at most {} are allowed per request", count, MAX_TAGS),
+            Error::NoTags => write!(f, "No tags applied, at least one is required per request"),
+            Error::UnknownCountry(ref country) => write!(f, "Unknown country {}", country),
         }
     }
 }
@@ -50,7 +99,8 @@ pub enum LastFMErrorResponse {
     InvalidService(LastFMError),
     /// Invalid Method - No method exists by the name provided.
     InvalidMethod(LastFMError),
-    /// Authentication Failed - Failed to authenticate with the Last.fm API.
+    /// Authentication Failed - Failed to authenticate with the Last.fm API. Also returned when the
+    /// username or password given to `auth.getMobileSession` is incorrect.
     AuthenticationFailed(LastFMError),
     /// Invalid Format - Service does not exist in the format given.
     InvalidFormat(LastFMError),
@@ -109,6 +159,16 @@ pub struct LastFMError {
     pub links: Option<Vec<String>>,
 }
 
+impl LastFMError {
+    /// Returns whether or not the given response body is an [`InvalidSessionKey`] error, meaning the
+    /// session key sent alongside the request was rejected.
+    ///
+    /// [`InvalidSessionKey`]: crate::error::LastFMErrorResponse::InvalidSessionKey
+    pub fn is_invalid_session(body: &str) -> bool {
+        matches!(serde_json::from_str::<LastFMError>(body), Ok(lastfm_error) if lastfm_error.error == 9)
+    }
+}
+
 impl From<LastFMError> for LastFMErrorResponse {
     fn from(lastm_error: LastFMError) -> LastFMErrorResponse {
         match lastm_error.error {
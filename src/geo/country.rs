@@ -0,0 +1,352 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// A country, as accepted by the geographic endpoints of the Last.fm API.
+///
+/// Last.fm expects countries to be given by their ISO 3166-1 name, e.g. "Korea, Republic of". A
+/// [Country] can be parsed from either its ISO 3166-1 alpha-2 or alpha-3 code, its ISO 3166-1
+/// name, or a few common alternative names such as "South Korea", case-insensitively.
+///
+/// Parsing an unknown country fails with [Error::UnknownCountry], so no request is ever made for
+/// a country Last.fm doesn't know about.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Country {
+    alpha2: &'static str,
+    alpha3: &'static str,
+    name: &'static str,
+}
+
+impl Country {
+    const fn new(alpha2: &'static str, alpha3: &'static str, name: &'static str) -> Country {
+        Country { alpha2, alpha3, name }
+    }
+
+    /// The ISO 3166-1 alpha-2 code of the country, e.g. "KR".
+    pub fn alpha2(&self) -> &'static str {
+        self.alpha2
+    }
+
+    /// The ISO 3166-1 alpha-3 code of the country, e.g. "KOR".
+    pub fn alpha3(&self) -> &'static str {
+        self.alpha3
+    }
+
+    /// The ISO 3166-1 name of the country, as sent to the Last.fm API.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Looks up a country by its ISO 3166-1 alpha-2 code.
+    fn by_alpha2(alpha2: &str) -> Option<Country> {
+        COUNTRIES.iter().find(|country| country.alpha2 == alpha2).copied()
+    }
+}
+
+impl FromStr for Country {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let query = s.trim();
+        let found = match query.len() {
+            2 => Country::by_alpha2(&query.to_ascii_uppercase()),
+            3 => COUNTRIES.iter().find(|country| country.alpha3.eq_ignore_ascii_case(query)).copied(),
+            _ => None,
+        };
+        let query = query.to_lowercase();
+        found
+            .or_else(|| COUNTRIES.iter().find(|country| country.name.to_lowercase() == query).copied())
+            .or_else(|| ALIASES.iter().find(|(alias, _)| alias.to_lowercase() == query).and_then(|(_, alpha2)| Country::by_alpha2(alpha2)))
+            .ok_or_else(|| Error::UnknownCountry(s.to_owned()))
+    }
+}
+
+impl Display for Country {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.name)
+    }
+}
+
+/// Common names of countries whose ISO 3166-1 name differs, mapped to their alpha-2 code.
+const ALIASES: &[(&str, &str)] = &[
+    ("Bolivia", "BO"),
+    ("Brunei", "BN"),
+    ("Cape Verde", "CV"),
+    ("Czechia", "CZ"),
+    ("East Timor", "TL"),
+    ("Great Britain", "GB"),
+    ("Iran", "IR"),
+    ("Ivory Coast", "CI"),
+    ("Laos", "LA"),
+    ("Macau", "MO"),
+    ("Macedonia", "MK"),
+    ("Micronesia", "FM"),
+    ("Moldova", "MD"),
+    ("North Korea", "KP"),
+    ("Palestine", "PS"),
+    ("Russia", "RU"),
+    ("South Korea", "KR"),
+    ("Swaziland", "SZ"),
+    ("Syria", "SY"),
+    ("Tanzania", "TZ"),
+    ("Türkiye", "TR"),
+    ("UK", "GB"),
+    ("United States of America", "US"),
+    ("Vatican City", "VA"),
+    ("Venezuela", "VE"),
+    ("Vietnam", "VN"),
+];
+
+/// All countries listed by ISO 3166-1.
+const COUNTRIES: &[Country] = &[
+    Country::new("AF", "AFG", "Afghanistan"),
+    Country::new("AX", "ALA", "Åland Islands"),
+    Country::new("AL", "ALB", "Albania"),
+    Country::new("DZ", "DZA", "Algeria"),
+    Country::new("AS", "ASM", "American Samoa"),
+    Country::new("AD", "AND", "Andorra"),
+    Country::new("AO", "AGO", "Angola"),
+    Country::new("AI", "AIA", "Anguilla"),
+    Country::new("AQ", "ATA", "Antarctica"),
+    Country::new("AG", "ATG", "Antigua and Barbuda"),
+    Country::new("AR", "ARG", "Argentina"),
+    Country::new("AM", "ARM", "Armenia"),
+    Country::new("AW", "ABW", "Aruba"),
+    Country::new("AU", "AUS", "Australia"),
+    Country::new("AT", "AUT", "Austria"),
+    Country::new("AZ", "AZE", "Azerbaijan"),
+    Country::new("BS", "BHS", "Bahamas"),
+    Country::new("BH", "BHR", "Bahrain"),
+    Country::new("BD", "BGD", "Bangladesh"),
+    Country::new("BB", "BRB", "Barbados"),
+    Country::new("BY", "BLR", "Belarus"),
+    Country::new("BE", "BEL", "Belgium"),
+    Country::new("BZ", "BLZ", "Belize"),
+    Country::new("BJ", "BEN", "Benin"),
+    Country::new("BM", "BMU", "Bermuda"),
+    Country::new("BT", "BTN", "Bhutan"),
+    Country::new("BO", "BOL", "Bolivia, Plurinational State of"),
+    Country::new("BQ", "BES", "Bonaire, Sint Eustatius and Saba"),
+    Country::new("BA", "BIH", "Bosnia and Herzegovina"),
+    Country::new("BW", "BWA", "Botswana"),
+    Country::new("BV", "BVT", "Bouvet Island"),
+    Country::new("BR", "BRA", "Brazil"),
+    Country::new("IO", "IOT", "British Indian Ocean Territory"),
+    Country::new("BN", "BRN", "Brunei Darussalam"),
+    Country::new("BG", "BGR", "Bulgaria"),
+    Country::new("BF", "BFA", "Burkina Faso"),
+    Country::new("BI", "BDI", "Burundi"),
+    Country::new("CV", "CPV", "Cabo Verde"),
+    Country::new("KH", "KHM", "Cambodia"),
+    Country::new("CM", "CMR", "Cameroon"),
+    Country::new("CA", "CAN", "Canada"),
+    Country::new("KY", "CYM", "Cayman Islands"),
+    Country::new("CF", "CAF", "Central African Republic"),
+    Country::new("TD", "TCD", "Chad"),
+    Country::new("CL", "CHL", "Chile"),
+    Country::new("CN", "CHN", "China"),
+    Country::new("CX", "CXR", "Christmas Island"),
+    Country::new("CC", "CCK", "Cocos (Keeling) Islands"),
+    Country::new("CO", "COL", "Colombia"),
+    Country::new("KM", "COM", "Comoros"),
+    Country::new("CG", "COG", "Congo"),
+    Country::new("CD", "COD", "Congo, the Democratic Republic of the"),
+    Country::new("CK", "COK", "Cook Islands"),
+    Country::new("CR", "CRI", "Costa Rica"),
+    Country::new("CI", "CIV", "Côte d'Ivoire"),
+    Country::new("HR", "HRV", "Croatia"),
+    Country::new("CU", "CUB", "Cuba"),
+    Country::new("CW", "CUW", "Curaçao"),
+    Country::new("CY", "CYP", "Cyprus"),
+    Country::new("CZ", "CZE", "Czech Republic"),
+    Country::new("DK", "DNK", "Denmark"),
+    Country::new("DJ", "DJI", "Djibouti"),
+    Country::new("DM", "DMA", "Dominica"),
+    Country::new("DO", "DOM", "Dominican Republic"),
+    Country::new("EC", "ECU", "Ecuador"),
+    Country::new("EG", "EGY", "Egypt"),
+    Country::new("SV", "SLV", "El Salvador"),
+    Country::new("GQ", "GNQ", "Equatorial Guinea"),
+    Country::new("ER", "ERI", "Eritrea"),
+    Country::new("EE", "EST", "Estonia"),
+    Country::new("SZ", "SWZ", "Eswatini"),
+    Country::new("ET", "ETH", "Ethiopia"),
+    Country::new("FK", "FLK", "Falkland Islands (Malvinas)"),
+    Country::new("FO", "FRO", "Faroe Islands"),
+    Country::new("FJ", "FJI", "Fiji"),
+    Country::new("FI", "FIN", "Finland"),
+    Country::new("FR", "FRA", "France"),
+    Country::new("GF", "GUF", "French Guiana"),
+    Country::new("PF", "PYF", "French Polynesia"),
+    Country::new("TF", "ATF", "French Southern Territories"),
+    Country::new("GA", "GAB", "Gabon"),
+    Country::new("GM", "GMB", "Gambia"),
+    Country::new("GE", "GEO", "Georgia"),
+    Country::new("DE", "DEU", "Germany"),
+    Country::new("GH", "GHA", "Ghana"),
+    Country::new("GI", "GIB", "Gibraltar"),
+    Country::new("GR", "GRC", "Greece"),
+    Country::new("GL", "GRL", "Greenland"),
+    Country::new("GD", "GRD", "Grenada"),
+    Country::new("GP", "GLP", "Guadeloupe"),
+    Country::new("GU", "GUM", "Guam"),
+    Country::new("GT", "GTM", "Guatemala"),
+    Country::new("GG", "GGY", "Guernsey"),
+    Country::new("GN", "GIN", "Guinea"),
+    Country::new("GW", "GNB", "Guinea-Bissau"),
+    Country::new("GY", "GUY", "Guyana"),
+    Country::new("HT", "HTI", "Haiti"),
+    Country::new("HM", "HMD", "Heard Island and McDonald Islands"),
+    Country::new("VA", "VAT", "Holy See (Vatican City State)"),
+    Country::new("HN", "HND", "Honduras"),
+    Country::new("HK", "HKG", "Hong Kong"),
+    Country::new("HU", "HUN", "Hungary"),
+    Country::new("IS", "ISL", "Iceland"),
+    Country::new("IN", "IND", "India"),
+    Country::new("ID", "IDN", "Indonesia"),
+    Country::new("IR", "IRN", "Iran, Islamic Republic of"),
+    Country::new("IQ", "IRQ", "Iraq"),
+    Country::new("IE", "IRL", "Ireland"),
+    Country::new("IM", "IMN", "Isle of Man"),
+    Country::new("IL", "ISR", "Israel"),
+    Country::new("IT", "ITA", "Italy"),
+    Country::new("JM", "JAM", "Jamaica"),
+    Country::new("JP", "JPN", "Japan"),
+    Country::new("JE", "JEY", "Jersey"),
+    Country::new("JO", "JOR", "Jordan"),
+    Country::new("KZ", "KAZ", "Kazakhstan"),
+    Country::new("KE", "KEN", "Kenya"),
+    Country::new("KI", "KIR", "Kiribati"),
+    Country::new("KP", "PRK", "Korea, Democratic People's Republic of"),
+    Country::new("KR", "KOR", "Korea, Republic of"),
+    Country::new("KW", "KWT", "Kuwait"),
+    Country::new("KG", "KGZ", "Kyrgyzstan"),
+    Country::new("LA", "LAO", "Lao People's Democratic Republic"),
+    Country::new("LV", "LVA", "Latvia"),
+    Country::new("LB", "LBN", "Lebanon"),
+    Country::new("LS", "LSO", "Lesotho"),
+    Country::new("LR", "LBR", "Liberia"),
+    Country::new("LY", "LBY", "Libya"),
+    Country::new("LI", "LIE", "Liechtenstein"),
+    Country::new("LT", "LTU", "Lithuania"),
+    Country::new("LU", "LUX", "Luxembourg"),
+    Country::new("MO", "MAC", "Macao"),
+    Country::new("MG", "MDG", "Madagascar"),
+    Country::new("MW", "MWI", "Malawi"),
+    Country::new("MY", "MYS", "Malaysia"),
+    Country::new("MV", "MDV", "Maldives"),
+    Country::new("ML", "MLI", "Mali"),
+    Country::new("MT", "MLT", "Malta"),
+    Country::new("MH", "MHL", "Marshall Islands"),
+    Country::new("MQ", "MTQ", "Martinique"),
+    Country::new("MR", "MRT", "Mauritania"),
+    Country::new("MU", "MUS", "Mauritius"),
+    Country::new("YT", "MYT", "Mayotte"),
+    Country::new("MX", "MEX", "Mexico"),
+    Country::new("FM", "FSM", "Micronesia, Federated States of"),
+    Country::new("MD", "MDA", "Moldova, Republic of"),
+    Country::new("MC", "MCO", "Monaco"),
+    Country::new("MN", "MNG", "Mongolia"),
+    Country::new("ME", "MNE", "Montenegro"),
+    Country::new("MS", "MSR", "Montserrat"),
+    Country::new("MA", "MAR", "Morocco"),
+    Country::new("MZ", "MOZ", "Mozambique"),
+    Country::new("MM", "MMR", "Myanmar"),
+    Country::new("NA", "NAM", "Namibia"),
+    Country::new("NR", "NRU", "Nauru"),
+    Country::new("NP", "NPL", "Nepal"),
+    Country::new("NL", "NLD", "Netherlands"),
+    Country::new("NC", "NCL", "New Caledonia"),
+    Country::new("NZ", "NZL", "New Zealand"),
+    Country::new("NI", "NIC", "Nicaragua"),
+    Country::new("NE", "NER", "Niger"),
+    Country::new("NG", "NGA", "Nigeria"),
+    Country::new("NU", "NIU", "Niue"),
+    Country::new("NF", "NFK", "Norfolk Island"),
+    Country::new("MK", "MKD", "North Macedonia"),
+    Country::new("MP", "MNP", "Northern Mariana Islands"),
+    Country::new("NO", "NOR", "Norway"),
+    Country::new("OM", "OMN", "Oman"),
+    Country::new("PK", "PAK", "Pakistan"),
+    Country::new("PW", "PLW", "Palau"),
+    Country::new("PS", "PSE", "Palestine, State of"),
+    Country::new("PA", "PAN", "Panama"),
+    Country::new("PG", "PNG", "Papua New Guinea"),
+    Country::new("PY", "PRY", "Paraguay"),
+    Country::new("PE", "PER", "Peru"),
+    Country::new("PH", "PHL", "Philippines"),
+    Country::new("PN", "PCN", "Pitcairn"),
+    Country::new("PL", "POL", "Poland"),
+    Country::new("PT", "PRT", "Portugal"),
+    Country::new("PR", "PRI", "Puerto Rico"),
+    Country::new("QA", "QAT", "Qatar"),
+    Country::new("RE", "REU", "Réunion"),
+    Country::new("RO", "ROU", "Romania"),
+    Country::new("RU", "RUS", "Russian Federation"),
+    Country::new("RW", "RWA", "Rwanda"),
+    Country::new("BL", "BLM", "Saint Barthélemy"),
+    Country::new("SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha"),
+    Country::new("KN", "KNA", "Saint Kitts and Nevis"),
+    Country::new("LC", "LCA", "Saint Lucia"),
+    Country::new("MF", "MAF", "Saint Martin (French part)"),
+    Country::new("PM", "SPM", "Saint Pierre and Miquelon"),
+    Country::new("VC", "VCT", "Saint Vincent and the Grenadines"),
+    Country::new("WS", "WSM", "Samoa"),
+    Country::new("SM", "SMR", "San Marino"),
+    Country::new("ST", "STP", "Sao Tome and Principe"),
+    Country::new("SA", "SAU", "Saudi Arabia"),
+    Country::new("SN", "SEN", "Senegal"),
+    Country::new("RS", "SRB", "Serbia"),
+    Country::new("SC", "SYC", "Seychelles"),
+    Country::new("SL", "SLE", "Sierra Leone"),
+    Country::new("SG", "SGP", "Singapore"),
+    Country::new("SX", "SXM", "Sint Maarten (Dutch part)"),
+    Country::new("SK", "SVK", "Slovakia"),
+    Country::new("SI", "SVN", "Slovenia"),
+    Country::new("SB", "SLB", "Solomon Islands"),
+    Country::new("SO", "SOM", "Somalia"),
+    Country::new("ZA", "ZAF", "South Africa"),
+    Country::new("GS", "SGS", "South Georgia and the South Sandwich Islands"),
+    Country::new("SS", "SSD", "South Sudan"),
+    Country::new("ES", "ESP", "Spain"),
+    Country::new("LK", "LKA", "Sri Lanka"),
+    Country::new("SD", "SDN", "Sudan"),
+    Country::new("SR", "SUR", "Suriname"),
+    Country::new("SJ", "SJM", "Svalbard and Jan Mayen"),
+    Country::new("SE", "SWE", "Sweden"),
+    Country::new("CH", "CHE", "Switzerland"),
+    Country::new("SY", "SYR", "Syrian Arab Republic"),
+    Country::new("TW", "TWN", "Taiwan"),
+    Country::new("TJ", "TJK", "Tajikistan"),
+    Country::new("TZ", "TZA", "Tanzania, United Republic of"),
+    Country::new("TH", "THA", "Thailand"),
+    Country::new("TL", "TLS", "Timor-Leste"),
+    Country::new("TG", "TGO", "Togo"),
+    Country::new("TK", "TKL", "Tokelau"),
+    Country::new("TO", "TON", "Tonga"),
+    Country::new("TT", "TTO", "Trinidad and Tobago"),
+    Country::new("TN", "TUN", "Tunisia"),
+    Country::new("TR", "TUR", "Turkey"),
+    Country::new("TM", "TKM", "Turkmenistan"),
+    Country::new("TC", "TCA", "Turks and Caicos Islands"),
+    Country::new("TV", "TUV", "Tuvalu"),
+    Country::new("UG", "UGA", "Uganda"),
+    Country::new("UA", "UKR", "Ukraine"),
+    Country::new("AE", "ARE", "United Arab Emirates"),
+    Country::new("GB", "GBR", "United Kingdom"),
+    Country::new("US", "USA", "United States"),
+    Country::new("UM", "UMI", "United States Minor Outlying Islands"),
+    Country::new("UY", "URY", "Uruguay"),
+    Country::new("UZ", "UZB", "Uzbekistan"),
+    Country::new("VU", "VUT", "Vanuatu"),
+    Country::new("VE", "VEN", "Venezuela, Bolivarian Republic of"),
+    Country::new("VN", "VNM", "Viet Nam"),
+    Country::new("VG", "VGB", "Virgin Islands, British"),
+    Country::new("VI", "VIR", "Virgin Islands, U.S."),
+    Country::new("WF", "WLF", "Wallis and Futuna"),
+    Country::new("EH", "ESH", "Western Sahara"),
+    Country::new("YE", "YEM", "Yemen"),
+    Country::new("ZM", "ZMB", "Zambia"),
+    Country::new("ZW", "ZWE", "Zimbabwe"),
+];
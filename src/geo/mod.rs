@@ -0,0 +1,21 @@
+//! Last.fm Geo API Endpoints
+//!
+//! Contains structs and methods related to working with the geographic endpoints
+//! available through the Last.fm API. Countries are given as a [Country], which can
+//! be parsed from ISO 3166-1 codes or names.
+//!
+//! [Country]: crate::geo::country::Country
+
+use serde::Deserialize;
+
+pub mod country;
+pub mod top_artists;
+pub mod top_tracks;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    #[serde(rename = "topartists")]
+    pub top_artists: Option<top_artists::TopArtists>,
+    #[serde(rename = "tracks")]
+    pub top_tracks: Option<top_tracks::TopTracks>,
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    geo::{country::Country, Endpoints},
+    model::{Artist, Attributes},
+    Client, RequestBuilder,
+};
+
+/// The main top artists structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the country and pagination attributes), and two,
+/// a list of the most popular artists in the country.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the artist information available, refer to [Artist].
+#[derive(Debug, Deserialize)]
+pub struct TopArtists {
+    /// The attributes associated with the country's Top Artists listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular artists in the country.
+    #[serde(rename = "artist")]
+    pub artists: Vec<Artist>,
+}
+
+impl TopArtists {
+    pub async fn build<'a>(client: &'a mut Client, country: Country) -> RequestBuilder<'a, TopArtists> {
+        let url = client.build_url(vec![("method", "geo.getTopArtists"), ("country", country.name())]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopArtists> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopArtists, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(geo) => Ok(geo.top_artists.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Retrieves the most popular artists in the given country. The country can be parsed from its ISO
+    /// 3166-1 code or name, see [Country].
+    ///
+    /// * `country` - The country to retrieve the most popular artists of.
+    pub async fn geo_top_artists(&'a mut self, country: Country) -> RequestBuilder<'a, TopArtists> {
+        TopArtists::build(self, country).await
+    }
+}
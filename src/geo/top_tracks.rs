@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    geo::{country::Country, Endpoints},
+    model::{Attributes, Track},
+    Client, RequestBuilder,
+};
+
+/// The main top tracks structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the country and pagination attributes), and two,
+/// a list of the most popular tracks in the country.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the track information available, refer to [Track].
+#[derive(Debug, Deserialize)]
+pub struct TopTracks {
+    /// The attributes associated with the country's Top Tracks listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular tracks in the country.
+    #[serde(rename = "track")]
+    pub tracks: Vec<Track>,
+}
+
+impl TopTracks {
+    pub async fn build<'a>(client: &'a mut Client, country: Country) -> RequestBuilder<'a, TopTracks> {
+        let url = client.build_url(vec![("method", "geo.getTopTracks"), ("country", country.name())]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTracks> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+    add_param!(with_location, location, &str);
+
+    pub async fn send(&'a mut self) -> Result<TopTracks, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(geo) => Ok(geo.top_tracks.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Retrieves the most popular tracks in the given country. The country can be parsed from its ISO
+    /// 3166-1 code or name, see [Country].
+    /// The listing can be narrowed down to a metro area of the country through the
+    /// `with_location` parameter function.
+    ///
+    /// * `country` - The country to retrieve the most popular tracks of.
+    pub async fn geo_top_tracks(&'a mut self, country: Country) -> RequestBuilder<'a, TopTracks> {
+        TopTracks::build(self, country).await
+    }
+}
@@ -6,10 +6,10 @@
 //! information retrieval. For all supported API endpoints, please check the Modules section; all API endpoints
 //! are organized by data type and/or category as reflected by the [Last.fm API Documentation].
 //!
-//! However, it should be noted that the Scrobbling API is not planned to be implemented in the near future,
-//! as music / media players written in Rust are fairly uncommon at this time, meaning support for scrobbling
-//! is not currently a high priority for the library. This can always change however, but the main focus is
-//! being as robust a library as possible for working with the heavily data-driven Last.fm API endpoints.
+//! Scrobbling is supported as well, through the `track.scrobble` and `track.updateNowPlaying` endpoints. As
+//! scrobbling requires an authenticated user, the client has to be given an API shared secret, and a session has
+//! to be retrieved through one of the flows described in the `auth` module. The `scrobbler` module contains helpers
+//! for music / media players, such as a queue keeping scrobbles around while the device is offline.
 //!
 //! The `error` and `model` modules are only used for error handling and models that are used across several
 //! API endpoints, so they can be ignored, unless you intend on implementing error handling in your application,
@@ -31,23 +31,47 @@ extern crate serde;
 extern crate serde_json;
 extern crate url;
 
-use reqwest::{Client as ReqwestClient, Error, Response};
+use auth::session::Session;
+use error::{Error, LastFMError};
+use reqwest::{Client as ReqwestClient, Response};
+use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
 use url::Url;
 
-pub mod error;
+// Declared before the endpoint modules, so the request builder macros are available to them.
 pub mod macros;
+
+pub mod album;
+pub mod artist;
+pub mod auth;
+pub mod chart;
+pub mod error;
+pub mod geo;
 pub mod model;
+pub mod scrobbler;
+pub mod tag;
 pub mod track;
 pub mod user;
 pub mod utilities;
 
 const WS_ENDPOINT: &str = "http://ws.audioscrobbler.com/2.0/";
+/// Signed requests carry the session key and, for `auth.getMobileSession`, the user's password, so
+/// they are only ever sent over HTTPS.
+const SIGNED_WS_ENDPOINT: &str = "https://ws.audioscrobbler.com/2.0/";
+const AUTH_ENDPOINT: &str = "https://www.last.fm/api/auth/";
+
+/// A hook invoked when the Last.fm API rejects the session key of the attached session.
+type SessionHook = Box<dyn FnMut() -> Pin<Box<dyn Future<Output = Option<Session>> + Send>> + Send + Sync>;
 
 /// The Request Builder.
 ///
 /// This is the main request builder, used for constructing any and all requests to the Last.fm API.
 ///
+/// Requests to read-only methods are sent as GET requests. Requests to authenticated or write methods
+/// are signed with the client's API shared secret and sent as form-encoded POST requests over HTTPS
+/// instead; the parameters are collected in the same way for both.
+///
 /// * `client` - An instance of the Last.fm API client.
 /// * `url` - The Last.fm API endpoint URL to feed to the request builder.
 /// * `phantom` - An unused parameter, only used to satisfy the type checker.
@@ -68,10 +92,19 @@ pub struct RequestBuilder<'a, T: 'a> {
 /// available methods can be seen below.
 ///
 /// * `api_key` - The API key used to authenticate to the Last.fm API.
+/// * `api_secret` - The API shared secret used to sign authenticated and write requests.
+/// * `session` - The authenticated session, if any, used for requests made on behalf of a user.
+/// * `session_hook` - Invoked when the attached session is rejected, in order to re-authenticate.
 /// * `client` - The given `reqwest` client. Used to send API requests.
 pub struct Client {
     /// The API key used to authenticate with Last.fm.
     api_key: String,
+    /// The API shared secret used to sign requests. Only required for authenticated and write methods.
+    api_secret: Option<String>,
+    /// The authenticated session. Its key is sent alongside any signed requests, except authentication ones.
+    session: Option<Session>,
+    /// Invoked when a signed request fails because the session key is invalid.
+    session_hook: Option<SessionHook>,
     /// The `reqwest` client. Used to transmit and receive API requests and responses.
     client: ReqwestClient,
 }
@@ -83,6 +116,24 @@ impl Client {
     pub fn new(api_key: &str) -> Client {
         Client {
             api_key: api_key.to_owned(),
+            api_secret: None,
+            session: None,
+            session_hook: None,
+            client: ReqwestClient::new(),
+        }
+    }
+
+    /// Initializes a new Last.fm API client able to sign requests, with a new `reqwest` client set to
+    /// defaults. A client initialized this way is required for authenticated and write methods.
+    ///
+    /// * `api_key` - The API key used to authenticate with the Last.fm API.
+    /// * `api_secret` - The API shared secret associated with the API key.
+    pub fn with_secret(api_key: &str, api_secret: &str) -> Client {
+        Client {
+            api_key: api_key.to_owned(),
+            api_secret: Some(api_secret.to_owned()),
+            session: None,
+            session_hook: None,
             client: ReqwestClient::new(),
         }
     }
@@ -96,14 +147,81 @@ impl Client {
     pub fn from_reqwest_client(client: ReqwestClient, api_key: &str) -> Client {
         Client {
             api_key: api_key.to_owned(),
+            api_secret: None,
+            session: None,
+            session_hook: None,
+            client,
+        }
+    }
+
+    /// Initializes a Last.fm API client able to sign requests from a pre-existing reqwest client.
+    ///
+    /// * `client` - The reqwest client to hook into.
+    /// * `api_key` - The API key used to authenticate with the Last.fm API.
+    /// * `api_secret` - The API shared secret associated with the API key.
+    pub fn from_reqwest_client_with_secret(client: ReqwestClient, api_key: &str, api_secret: &str) -> Client {
+        Client {
+            api_key: api_key.to_owned(),
+            api_secret: Some(api_secret.to_owned()),
+            session: None,
+            session_hook: None,
             client,
         }
     }
 
+    /// Attaches an authenticated [`Session`] to the client. Signed requests made afterwards are made on
+    /// behalf of the session's user.
+    ///
+    /// * `session` - The session to attach, as returned by one of the authentication methods.
+    ///
+    /// [`Session`]: crate::auth::session::Session
+    pub fn set_session(&mut self, session: Session) {
+        self.session = Some(session);
+    }
+
+    /// Returns the [`Session`] currently attached to the client, if any.
+    ///
+    /// [`Session`]: crate::auth::session::Session
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    /// Registers a hook invoked when a signed request fails with an [`InvalidSessionKey`] error, e.g.
+    /// because the user revoked the application's access. The hook should re-authenticate the user and
+    /// return the new [`Session`], in which case the session is attached to the client and the request
+    /// is retried once. If the hook returns [None], the original error is returned instead.
+    ///
+    /// * `hook` - The hook to invoke.
+    ///
+    /// [`InvalidSessionKey`]: crate::error::LastFMErrorResponse::InvalidSessionKey
+    /// [`Session`]: crate::auth::session::Session
+    pub fn on_invalid_session<F, Fut>(&mut self, hook: F)
+    where
+        F: FnMut() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<Session>> + Send + 'static,
+    {
+        let mut hook = hook;
+        self.session_hook = Some(Box::new(move || Box::pin(hook())));
+    }
+
+    /// Sends a signed request to the given Last.fm API method, returning the raw body of the response.
+    ///
+    /// This is intended for authenticated or write methods that aren't wrapped by the library yet. Errors
+    /// returned by the Last.fm API are not parsed, and are included in the body as-is.
+    ///
+    /// * `method` - The Last.fm API method to call, e.g. `track.love`.
+    /// * `params` - The parameters to send alongside the method. `api_key` is added automatically.
+    pub async fn signed_call(&mut self, method: &str, params: Vec<(&str, &str)>) -> Result<String, Error> {
+        let mut params = params;
+        params.insert(0, ("method", method));
+        let url = self.build_url(params).await;
+        self.signed_request(&url).await
+    }
+
     /// Build a new URL with the given query parameters pointing to a given Last.fm API endpoint.
     async fn build_url(&self, params: Vec<(&str, &str)>) -> Url {
         let mut url = Url::parse(WS_ENDPOINT).unwrap();
-        url.query_pairs_mut().clear().append_pair("api_key", &*self.api_key).append_pair("format", "json");
+        url.query_pairs_mut().clear().append_pair("api_key", &self.api_key).append_pair("format", "json");
         for (key, value) in params {
             url.query_pairs_mut().append_pair(key, value);
         }
@@ -113,7 +231,131 @@ impl Client {
     /// Send a GET request to the provided [`Url`].
     ///
     /// [`Url`]: url::Url
-    async fn request(&mut self, url: &Url) -> Result<Response, Error> {
+    async fn request(&mut self, url: &Url) -> Result<Response, reqwest::Error> {
         self.client.get(url.as_str()).send().await
     }
+
+    /// Sign the query parameters of the provided [`Url`] and send them to the Last.fm API as a
+    /// form-encoded POST request, returning the body of the response. If the session key is rejected,
+    /// the session hook is given a chance to re-authenticate before the request is retried.
+    ///
+    /// [`Url`]: url::Url
+    async fn signed_request(&mut self, url: &Url) -> Result<String, Error> {
+        let body = self.send_signed(url).await?;
+        if LastFMError::is_invalid_session(&body) && self.renew_session().await {
+            return self.send_signed(url).await;
+        }
+        Ok(body)
+    }
+
+    /// Invokes the hook registered with [`Client::on_invalid_session`], attaching the session it
+    /// returns to the client. Returns whether or not a new session was attached, in which case the
+    /// rejected request is worth retrying. Signed requests call this automatically when the session
+    /// key is rejected, but it can also be used to re-authenticate ahead of time.
+    pub async fn renew_session(&mut self) -> bool {
+        let session = match self.session_hook.as_mut() {
+            Some(hook) => hook().await,
+            None => None,
+        };
+        match session {
+            Some(session) => {
+                self.session = Some(session);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sign the query parameters of the provided [`Url`] and send them to the Last.fm API as a
+    /// form-encoded POST request, returning the body of the response.
+    ///
+    /// [`Url`]: url::Url
+    async fn send_signed(&self, url: &Url) -> Result<String, Error> {
+        let request = self.signed_post(url)?;
+        let response = self.client.execute(request).await.map_err(Error::HTTPError)?;
+        response.text().await.map_err(Error::HTTPError)
+    }
+
+    /// Build the form-encoded POST request carrying the signed query parameters of the provided
+    /// [`Url`]. The key of the attached session is added to the parameters, unless the request is
+    /// an authentication request.
+    ///
+    /// [`Url`]: url::Url
+    fn signed_post(&self, url: &Url) -> Result<reqwest::Request, Error> {
+        let api_secret = self.api_secret.as_deref().ok_or(Error::MissingSecret)?;
+
+        let mut params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        let authenticating = params.iter().any(|(key, value)| key == "method" && value.starts_with("auth."));
+        if !authenticating {
+            if let Some(session) = &self.session {
+                params.push(("sk".to_owned(), session.key.clone()));
+            }
+        }
+        let signature = utilities::sign(&params, api_secret);
+        params.push(("api_sig".to_owned(), signature));
+
+        self.client.post(SIGNED_WS_ENDPOINT).form(&params).build().map_err(Error::HTTPError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Method;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    /// Polls a future which is expected to complete without waiting on any I/O.
+    fn now<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future did not complete immediately"),
+        }
+    }
+
+    fn body(request: &reqwest::Request) -> &str {
+        std::str::from_utf8(request.body().and_then(|body| body.as_bytes()).expect("Reading body")).expect("Decoding body")
+    }
+
+    #[test]
+    fn mobile_session_sent_over_https() {
+        let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+        let builder = now(client.mobile_session("tzuwy", "hunter2"));
+        let request = builder.client.signed_post(&builder.url).expect("Building request");
+
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.url().as_str(), SIGNED_WS_ENDPOINT);
+        assert!(body(&request).contains("method=auth.getMobileSession"));
+        assert!(body(&request).contains("password=hunter2"));
+        assert!(!body(&request).contains("sk="));
+    }
+
+    #[test]
+    fn signed_post_uses_renewed_session() {
+        let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+        client.set_session(Session {
+            name: "tzuwy".to_owned(),
+            key: "d580d57f32848f5dcf574d1ce18d78b2".to_owned(),
+            subscriber: false,
+        });
+        client.on_invalid_session(|| async {
+            Some(Session {
+                name: "tzuwy".to_owned(),
+                key: "0123456789abcdef0123456789abcdef".to_owned(),
+                subscriber: false,
+            })
+        });
+        assert!(now(client.renew_session()));
+
+        let builder = now(client.love_track("Cher", "Believe"));
+        let request = builder.client.signed_post(&builder.url).expect("Building request");
+        assert!(body(&request).contains("sk=0123456789abcdef0123456789abcdef"));
+    }
+
+    #[test]
+    fn signed_post_without_secret() {
+        let client = Client::new("xxxxxxxxxx");
+        let url = Url::parse(WS_ENDPOINT).unwrap();
+        assert!(matches!(client.signed_post(&url), Err(Error::MissingSecret)));
+    }
 }
@@ -4,8 +4,88 @@
 macro_rules! add_param {
     ($f:ident, $p:ident, $t:ty) => {
         pub fn $f(&'a mut self, v: $t) -> &'a mut Self {
-            self.url.query_pairs_mut().append_pair(stringify!($p), &*v.to_string());
+            self.url.query_pairs_mut().append_pair(stringify!($p), &v.to_string());
             self
         }
     };
 }
+
+macro_rules! add_flag {
+    ($f:ident, $p:ident) => {
+        pub fn $f(&'a mut self, v: bool) -> &'a mut Self {
+            self.url.query_pairs_mut().append_pair(stringify!($p), if v { "1" } else { "0" });
+            self
+        }
+    };
+}
+
+/// Implements `send` for the `Tags`, `TopTags`, `AddTags` and `RemoveTag` request builders of an
+/// endpoint module. Each module declares its own types and `Endpoints`, which are resolved where the
+/// macro is invoked.
+macro_rules! tag_requests {
+    () => {
+        impl<'a> RequestBuilder<'a, Tags> {
+            add_param!(with_user, user, &str);
+            add_flag!(with_autocorrect, autocorrect);
+
+            /// Sends the request. Unless a user was given through the `with_user` parameter function, the
+            /// tags of the authenticated user are retrieved, in which case the request is signed.
+            pub async fn send(&'a mut self) -> Result<Tags, Error> {
+                let body = if self.url.query_pairs().any(|(key, _)| key == "user") {
+                    let response = self.client.request(&self.url).await.map_err(Error::HTTPError)?;
+                    response.text().await.map_err(Error::HTTPError)?
+                } else {
+                    self.client.signed_request(&self.url).await?
+                };
+
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(endpoints) => Ok(endpoints.tags.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+        }
+
+        impl<'a> RequestBuilder<'a, TopTags> {
+            add_flag!(with_autocorrect, autocorrect);
+
+            pub async fn send(&'a mut self) -> Result<TopTags, Error> {
+                match self.client.request(&self.url).await {
+                    Ok(response) => {
+                        let body = response.text().await.unwrap();
+                        match serde_json::from_str::<LastFMError>(&body) {
+                            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                            Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                                Ok(endpoints) => Ok(endpoints.top_tags.unwrap()),
+                                Err(e) => Err(Error::ParsingError(e)),
+                            },
+                        }
+                    }
+                    Err(err) => Err(Error::HTTPError(err)),
+                }
+            }
+        }
+
+        impl<'a> RequestBuilder<'a, AddTags> {
+            pub async fn send(&'a mut self) -> Result<(), Error> {
+                let body = self.client.signed_request(&self.url).await?;
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => Ok(()),
+                }
+            }
+        }
+
+        impl<'a> RequestBuilder<'a, RemoveTag> {
+            pub async fn send(&'a mut self) -> Result<(), Error> {
+                let body = self.client.signed_request(&self.url).await?;
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => Ok(()),
+                }
+            }
+        }
+    };
+}
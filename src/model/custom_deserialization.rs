@@ -4,6 +4,7 @@
 // the `string_or_struct` function uses these impl to instantiate a Type
 // if the input file contains a string and not a struct.
 
+use crate::model::Tag;
 use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::fmt;
@@ -83,3 +84,92 @@ where
 
     deserializer.deserialize_any(StringOrStruct(PhantomData))
 }
+
+/// Deserializes a list of items which the Last.fm API transmits as a single object instead of an
+/// array when it only contains one item.
+pub fn one_or_many<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(items) => Ok(items),
+        OneOrMany::One(item) => Ok(vec![item]),
+    }
+}
+
+/// Deserializes an object, which the Last.fm API replaces with a string, e.g. `"\n"`, when it has
+/// nothing to transmit. Strings are deserialized as [None], while objects that fail to deserialize
+/// still result in an error.
+fn object_or_string<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct ObjectOrString<T>(PhantomData<fn() -> T>);
+
+    impl<'de, T> Visitor<'de> for ObjectOrString<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Option<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("string or map")
+        }
+
+        fn visit_str<E>(self, _: &str) -> Result<Option<T>, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_map<M>(self, map: M) -> Result<Option<T>, M::Error>
+        where
+            M: MapAccess<'de>,
+        {
+            Deserialize::deserialize(de::value::MapAccessDeserializer::new(map)).map(Some)
+        }
+    }
+
+    deserializer.deserialize_any(ObjectOrString(PhantomData))
+}
+
+/// Deserializes a list of tags, which the Last.fm API transmits nested under a `tag` field, e.g.
+/// `{"tag": [...]}`. Lists without any tags are sometimes transmitted as a string instead.
+pub fn tag_list<'de, D>(deserializer: D) -> Result<Vec<Tag>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Tags {
+        #[serde(default, deserialize_with = "one_or_many")]
+        tag: Vec<Tag>,
+    }
+
+    Ok(object_or_string::<Tags, D>(deserializer)?.map(|tags| tags.tag).unwrap_or_default())
+}
+
+/// Deserializes the correction transmitted by the `getCorrection` endpoints, which is nested under
+/// a `correction` field. When Last.fm has no correction to offer, the correction is missing or
+/// transmitted as a string instead.
+pub fn correction<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Corrections<T> {
+        correction: Option<T>,
+    }
+
+    Ok(object_or_string::<Corrections<T>, D>(deserializer)?.and_then(|corrections| corrections.correction))
+}
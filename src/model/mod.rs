@@ -5,11 +5,11 @@
 
 use crate::model::custom_deserialization::{option_string_or_struct, string_or_struct};
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use std::str::FromStr;
 use void::Void;
 
-use crate::utilities::deserialize_datetime_from_str;
+use crate::utilities::{deserialize_bool, deserialize_datetime_from_str, deserialize_option_f32, deserialize_option_u64, deserialize_u64};
 
 pub mod custom_deserialization;
 
@@ -20,8 +20,14 @@ pub struct Attributes {
     pub page: String,
     /// The total amount of items.
     pub total: String,
-    /// The user associated with the given item.
-    pub user: String,
+    /// The user associated with the given item, for user-related endpoints.
+    pub user: Option<String>,
+    /// The artist associated with the given item, for artist-related endpoints.
+    pub artist: Option<String>,
+    /// The tag associated with the given item, for tag-related endpoints.
+    pub tag: Option<String>,
+    /// The country associated with the given item, for geographic endpoints.
+    pub country: Option<String>,
     /// The amount of items listed in a single page.
     #[serde(rename = "perPage")]
     pub per_page: String,
@@ -30,6 +36,20 @@ pub struct Attributes {
     pub total_pages: String,
 }
 
+/// The OpenSearch metadata transmitted alongside the results of the search endpoints.
+#[derive(Debug, Deserialize)]
+pub struct OpenSearch {
+    /// The total amount of results matching the search.
+    #[serde(rename = "opensearch:totalResults", deserialize_with = "deserialize_u64")]
+    pub total_results: u64,
+    /// The index of the first result in the current page.
+    #[serde(rename = "opensearch:startIndex", deserialize_with = "deserialize_u64")]
+    pub start_index: u64,
+    /// The amount of results listed in a single page.
+    #[serde(rename = "opensearch:itemsPerPage", deserialize_with = "deserialize_u64")]
+    pub items_per_page: u64,
+}
+
 /// The Date object. Consists of a raw UTC date (able to be formatted), and an already
 /// formatted date string ready to be used. The raw date uses the chrono date & time
 /// library to format the date.
@@ -77,6 +97,10 @@ pub struct Track {
     /// The number of times this track has been played in the given time period
     /// Not always returned, for example, loved tracks do not include a playcount
     pub playcount: Option<String>,
+    /// The amount of unique listeners of the track.
+    /// Returned when output comes from some endpoints such as charts
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub listeners: Option<u64>,
     /// The album the track is associated with.
     pub album: Option<Album>,
     /// The last.fm URL of the track.
@@ -101,6 +125,9 @@ pub struct TrackAttributes {
     /// one the user is currently playing.
     #[serde(rename = "nowplaying")]
     pub now_playing: Option<String>,
+    /// Where the track is ranked in the given listing, e.g. an artist's top tracks. This is the raw
+    /// value returned by Last.fm, so whether ranks start at 0 or 1 depends on the endpoint.
+    pub rank: Option<String>,
 }
 
 impl FromStr for Streamable {
@@ -117,8 +144,31 @@ impl FromStr for Streamable {
 #[derive(Debug, Deserialize)]
 pub struct Album {
     /// The name of the album.
-    #[serde(rename = "#text")]
+    #[serde(alias = "#text")]
     pub name: String,
+    /// The artist who published the given album.
+    #[serde(default, deserialize_with = "option_string_or_struct")]
+    pub artist: Option<Artist>,
+    /// The last.fm URL for the given album.
+    pub url: Option<String>,
+    /// The MusicBrainz ID of the given album.
+    pub mbid: Option<String>,
+    /// Attributes associated with the album.
+    #[serde(rename = "@attr")]
+    pub attrs: Option<AlbumAttributes>,
+    /// How many times the album has been played.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub playcount: Option<u64>,
+    /// The main images linked to the album.
+    #[serde(rename = "image")]
+    pub images: Option<Vec<Image>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumAttributes {
+    /// Where the album is ranked in the given listing, e.g. an artist's top albums. See
+    /// [TrackAttributes::rank].
+    pub rank: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -136,9 +186,17 @@ pub struct Artist {
     /// How many times the user has scrobbled the artist.
     #[serde(rename = "playcount")]
     pub scrobbles: Option<String>,
+    /// The amount of unique listeners of the artist.
+    /// Returned when output comes from some endpoints such as charts
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub listeners: Option<u64>,
     /// The main images linked to the artist.
     #[serde(rename = "image")]
     pub images: Option<Vec<Image>>,
+    /// How similar the artist is to the one the request was made for, ranging from 0 to 1.
+    /// Returned when output comes from some endpoints such as similar
+    #[serde(default, deserialize_with = "deserialize_option_f32")]
+    pub r#match: Option<f32>,
 }
 
 impl FromStr for Artist {
@@ -151,14 +209,17 @@ impl FromStr for Artist {
             mbid: None,
             attrs: None,
             scrobbles: None,
+            listeners: None,
             images: None,
+            r#match: None,
         })
     }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ArtistAttributes {
-    /// Where the artist is ranked in the user's profile.
+    /// Where the artist is ranked in the given listing, e.g. the user's profile. See
+    /// [TrackAttributes::rank].
     pub rank: Option<String>,
 }
 
@@ -171,3 +232,95 @@ pub struct Streamable {
     #[serde(rename = "#text")]
     pub text: String,
 }
+
+/// The maximum amount of tags the Last.fm API accepts in a single request when tagging an item.
+pub const MAX_TAGS: usize = 10;
+
+/// A tag, as applied to tracks, artists and albums by Last.fm users.
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    /// The name of the tag.
+    pub name: String,
+    /// The Last.fm URL of the tag.
+    pub url: Option<String>,
+    /// How many times the tag was applied, relative to the most applied tag, which has a count
+    /// of 100. Only transmitted by the endpoints retrieving the top tags of an item. In the global
+    /// top tags listing, this is the total amount of times the tag was applied instead.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub count: Option<u64>,
+    /// The amount of unique users who applied the tag. Only transmitted by the global tag listings.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub reach: Option<u64>,
+    /// The total amount of times the tag was applied. Only transmitted by the global tag charts.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub taggings: Option<u64>,
+}
+
+/// The wiki of a track, album or tag, written by Last.fm users.
+#[derive(Debug, Deserialize)]
+pub struct Wiki {
+    /// The date the wiki was last published, e.g. "27 Jul 2008, 15:55".
+    pub published: Option<String>,
+    /// A short summary of the wiki.
+    pub summary: String,
+    /// The full content of the wiki.
+    pub content: String,
+}
+
+/// A value submitted to the Last.fm API, such as the artist or track name of a scrobble, which
+/// Last.fm may have corrected.
+#[derive(Debug, Deserialize)]
+pub struct Corrected {
+    /// Whether or not Last.fm corrected the submitted value.
+    #[serde(deserialize_with = "deserialize_bool")]
+    pub corrected: bool,
+    /// The value as stored by Last.fm, after any corrections. Empty if no value was submitted.
+    #[serde(rename = "#text", default)]
+    pub text: String,
+}
+
+/// Explains why a scrobble or now playing update was ignored by Last.fm.
+#[derive(Debug, Deserialize)]
+pub struct IgnoredMessage {
+    /// The reason for which the submission was ignored.
+    pub code: IgnoredCode,
+    /// A human-readable description of the reason. Empty if the submission wasn't ignored.
+    #[serde(rename = "#text", default)]
+    pub message: String,
+}
+
+/// The reasons for which Last.fm may ignore a scrobble or now playing update.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IgnoredCode {
+    /// The submission was not ignored.
+    NotIgnored,
+    /// The artist was ignored, e.g. because it is on Last.fm's list of filtered artists.
+    ArtistIgnored,
+    /// The track was ignored, e.g. because it is on Last.fm's list of filtered tracks.
+    TrackIgnored,
+    /// The timestamp of the scrobble is too far in the past.
+    TimestampTooOld,
+    /// The timestamp of the scrobble is too far in the future.
+    TimestampTooNew,
+    /// The user has exceeded the maximum amount of scrobbles allowed per day.
+    DailyLimitExceeded,
+    /// A reason not documented by Last.fm at the time of writing.
+    Other(u64),
+}
+
+impl<'de> Deserialize<'de> for IgnoredCode {
+    fn deserialize<D>(deserializer: D) -> Result<IgnoredCode, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match deserialize_u64(deserializer)? {
+            0 => IgnoredCode::NotIgnored,
+            1 => IgnoredCode::ArtistIgnored,
+            2 => IgnoredCode::TrackIgnored,
+            3 => IgnoredCode::TimestampTooOld,
+            4 => IgnoredCode::TimestampTooNew,
+            5 => IgnoredCode::DailyLimitExceeded,
+            code => IgnoredCode::Other(code),
+        })
+    }
+}
@@ -0,0 +1,10 @@
+//! Scrobbling Helpers
+//!
+//! Contains helpers intended for music / media players that scrobble tracks to Last.fm, built
+//! on top of the `track.scrobble` endpoint. Scrobbles submitted through these helpers are made on
+//! behalf of the session attached to the given [Client].
+//!
+//! [Client]: crate::Client
+
+pub mod player;
+pub mod queue;
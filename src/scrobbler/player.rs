@@ -0,0 +1,229 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+use crate::track::scrobble::Scrobble;
+
+/// The minimum length a track needs to exceed in order to be scrobbled.
+const MIN_DURATION: Duration = Duration::from_secs(30);
+/// The amount of time after which a track qualifies for scrobbling, regardless of its length.
+const MAX_PLAYED: Duration = Duration::from_secs(4 * 60);
+
+/// A source of the current time. Time is injected into the [Scrobbler] through this trait, so
+/// that the scrobbling rules can be exercised without having to wait for tracks to play.
+pub trait Clock {
+    /// Returns the current time, in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock. This is the clock used by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+/// Metadata about a track played by a media player.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackMetadata {
+    /// The name of the artist who performed the track.
+    pub artist: String,
+    /// The name of the track.
+    pub track: String,
+    /// The name of the album the track appears on.
+    pub album: Option<String>,
+    /// The album artist, if it differs from the track artist.
+    pub album_artist: Option<String>,
+    /// The position of the track on the album.
+    pub track_number: Option<u32>,
+    /// The MusicBrainz ID of the track.
+    pub mbid: Option<String>,
+    /// The length of the track.
+    pub duration: Duration,
+}
+
+/// The events a media player reports to the [Scrobbler].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayerEvent {
+    /// A track started playing. If another track was playing, it is considered stopped.
+    Started(TrackMetadata),
+    /// Playback of the current track was paused.
+    Paused,
+    /// Playback of the current track was resumed.
+    Resumed,
+    /// The user seeked to the given position in the current track. Seeking doesn't count towards the
+    /// time the track has been played, only the time actually spent playing does.
+    Seeked(Duration),
+    /// Playback of the current track was stopped.
+    Stopped,
+}
+
+/// The requests the [Scrobbler] asks the media player to make to Last.fm.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScrobblerAction {
+    /// The user's now playing status should be updated through [Client::update_now_playing].
+    ///
+    /// [Client::update_now_playing]: crate::Client::update_now_playing
+    NowPlaying(TrackMetadata),
+    /// The track qualified for scrobbling, and should be scrobbled through [Client::scrobble] or
+    /// a [ScrobbleQueue].
+    ///
+    /// [Client::scrobble]: crate::Client::scrobble
+    /// [ScrobbleQueue]: crate::scrobbler::queue::ScrobbleQueue
+    Scrobble(Scrobble),
+}
+
+/// The playback state of the current track.
+#[derive(Debug)]
+struct Playback {
+    /// The track being played.
+    track: TrackMetadata,
+    /// The time the track started playing, which is the timestamp of its scrobble.
+    started_at: DateTime<Utc>,
+    /// The time spent playing the track, up until it was last paused.
+    played: Duration,
+    /// The time playback was last started or resumed. [None] while paused.
+    resumed_at: Option<DateTime<Utc>>,
+    /// Whether or not the track was scrobbled already.
+    scrobbled: bool,
+}
+
+impl Playback {
+    /// Returns the time spent playing the track so far.
+    fn played(&self, now: DateTime<Utc>) -> Duration {
+        match self.resumed_at {
+            Some(resumed_at) => self.played + (now - resumed_at).to_std().unwrap_or_default(),
+            None => self.played,
+        }
+    }
+
+    /// Returns whether or not the track qualifies for scrobbling: the track has to be longer than 30
+    /// seconds, and has to have been played for at least half its length, or for four minutes.
+    fn qualifies(&self, now: DateTime<Utc>) -> bool {
+        self.track.duration > MIN_DURATION && self.played(now) >= (self.track.duration / 2).min(MAX_PLAYED)
+    }
+
+    fn scrobble(&self) -> Scrobble {
+        Scrobble {
+            album: self.track.album.clone(),
+            album_artist: self.track.album_artist.clone(),
+            track_number: self.track.track_number,
+            duration: Some(self.track.duration.as_secs() as u32),
+            mbid: self.track.mbid.clone(),
+            ..Scrobble::new(&self.track.artist, &self.track.track, self.started_at)
+        }
+    }
+}
+
+/// Decides when tracks played by a media player should be scrobbled.
+///
+/// The scrobbler consumes the events reported by the player through [Scrobbler::handle], and returns
+/// the requests the player should make to Last.fm in response. A track is scrobbled once it qualifies
+/// under Last.fm's rules, either when it stops playing, or as soon as it qualifies if the player
+/// calls [Scrobbler::poll] periodically. Each track is scrobbled at most once per time it's started.
+#[derive(Debug)]
+pub struct Scrobbler<C: Clock = SystemClock> {
+    /// The clock used to measure how long tracks have been played.
+    clock: C,
+    /// The playback state of the current track, if any.
+    current: Option<Playback>,
+}
+
+impl Scrobbler<SystemClock> {
+    /// Creates a new scrobbler measuring time with the system clock.
+    pub fn new() -> Scrobbler<SystemClock> {
+        Scrobbler::with_clock(SystemClock)
+    }
+}
+
+impl Default for Scrobbler<SystemClock> {
+    fn default() -> Scrobbler<SystemClock> {
+        Scrobbler::new()
+    }
+}
+
+impl<C: Clock> Scrobbler<C> {
+    /// Creates a new scrobbler measuring time with the given clock.
+    ///
+    /// * `clock` - The clock to measure time with.
+    pub fn with_clock(clock: C) -> Scrobbler<C> {
+        Scrobbler { clock, current: None }
+    }
+
+    /// Returns the track currently playing or paused, if any.
+    pub fn current(&self) -> Option<&TrackMetadata> {
+        self.current.as_ref().map(|playback| &playback.track)
+    }
+
+    /// Handles an event reported by the media player, returning the requests the player should make
+    /// to Last.fm in response.
+    ///
+    /// * `event` - The event to handle.
+    pub fn handle(&mut self, event: PlayerEvent) -> Vec<ScrobblerAction> {
+        let now = self.clock.now();
+        let mut actions = Vec::new();
+
+        match event {
+            PlayerEvent::Started(track) => {
+                actions.extend(self.finish(now));
+                actions.push(ScrobblerAction::NowPlaying(track.clone()));
+                self.current = Some(Playback {
+                    track,
+                    started_at: now,
+                    played: Duration::ZERO,
+                    resumed_at: Some(now),
+                    scrobbled: false,
+                });
+            }
+            PlayerEvent::Paused => {
+                if let Some(playback) = self.current.as_mut() {
+                    playback.played = playback.played(now);
+                    playback.resumed_at = None;
+                }
+            }
+            PlayerEvent::Resumed => {
+                if let Some(playback) = self.current.as_mut() {
+                    if playback.resumed_at.is_none() {
+                        playback.resumed_at = Some(now);
+                    }
+                }
+            }
+            PlayerEvent::Seeked(_) => {}
+            PlayerEvent::Stopped => actions.extend(self.finish(now)),
+        }
+
+        actions
+    }
+
+    /// Checks whether or not the current track qualified for scrobbling since it was last checked,
+    /// returning the scrobble if so. Players should call this periodically, e.g. once per second, in
+    /// order to scrobble tracks as soon as they qualify.
+    pub fn poll(&mut self) -> Option<ScrobblerAction> {
+        let now = self.clock.now();
+        let playback = self.current.as_mut()?;
+        if playback.scrobbled || !playback.qualifies(now) {
+            return None;
+        }
+
+        playback.scrobbled = true;
+        Some(ScrobblerAction::Scrobble(playback.scrobble()))
+    }
+
+    /// Stops the current track, returning its scrobble if it qualified and wasn't scrobbled yet.
+    fn finish(&mut self, now: DateTime<Utc>) -> Option<ScrobblerAction> {
+        let playback = self.current.take()?;
+        if playback.scrobbled || !playback.qualifies(now) {
+            return None;
+        }
+
+        Some(ScrobblerAction::Scrobble(playback.scrobble()))
+    }
+}
@@ -0,0 +1,220 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    error::Error,
+    model::IgnoredCode,
+    track::scrobble::{Scrobble, Scrobbles, MAX_SCROBBLES},
+    Client,
+};
+
+/// A durable queue of scrobbles waiting to be submitted to Last.fm.
+///
+/// Pending scrobbles are kept in a journal file, one JSON object per line, so that they survive
+/// process restarts. Scrobbles are only removed from the queue once Last.fm has either accepted
+/// or permanently ignored them; if a submission fails, e.g. because the device is offline, the
+/// scrobbles are kept and can be submitted again later through [ScrobbleQueue::flush].
+#[derive(Debug)]
+pub struct ScrobbleQueue {
+    /// The path of the journal file.
+    path: PathBuf,
+    /// The scrobbles waiting to be submitted, oldest first.
+    pending: Vec<Scrobble>,
+}
+
+impl ScrobbleQueue {
+    /// Opens the queue backed by the given journal file, loading any scrobbles left pending by a
+    /// previous run. The journal file is created when the first scrobble is queued.
+    ///
+    /// Lines of the journal that can't be parsed, such as one left partially written by a crash,
+    /// are skipped.
+    ///
+    /// * `path` - The path of the journal file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<ScrobbleQueue, Error> {
+        let path = path.as_ref().to_path_buf();
+        let mut pending = Vec::new();
+        let mut skipped = false;
+        match File::open(&path) {
+            Ok(file) => {
+                for line in BufReader::new(file).lines() {
+                    match serde_json::from_str::<Scrobble>(&line.map_err(Error::IOError)?) {
+                        Ok(scrobble) => pending.push(scrobble),
+                        Err(_) => skipped = true,
+                    }
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(Error::IOError(e)),
+        }
+
+        let queue = ScrobbleQueue { path, pending };
+        // Drop the unparseable lines, so scrobbles queued from now on aren't appended to a partial line.
+        if skipped {
+            queue.rewrite()?;
+        }
+        Ok(queue)
+    }
+
+    /// Returns the scrobbles waiting to be submitted, oldest first.
+    pub fn pending(&self) -> &[Scrobble] {
+        &self.pending
+    }
+
+    /// Returns the amount of scrobbles waiting to be submitted.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns whether or not the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Adds a scrobble to the end of the queue. The scrobble is written to the journal before this
+    /// method returns.
+    ///
+    /// * `scrobble` - The scrobble to queue.
+    pub fn push(&mut self, scrobble: Scrobble) -> Result<(), Error> {
+        let mut line = serde_json::to_string(&scrobble).map_err(Error::ParsingError)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path).map_err(Error::IOError)?;
+        file.write_all(line.as_bytes()).map_err(Error::IOError)?;
+        file.sync_all().map_err(Error::IOError)?;
+
+        self.pending.push(scrobble);
+        Ok(())
+    }
+
+    /// Removes the first `count` pending scrobbles from the queue, returning them.
+    ///
+    /// This is intended for batches Last.fm permanently rejects as a whole, e.g. with an
+    /// [`InvalidParameters`] error, which would otherwise make every later flush fail on the same
+    /// batch. The rejected batch is always made of the oldest pending scrobbles, up to [MAX_SCROBBLES].
+    ///
+    /// * `count` - The amount of scrobbles to remove. Clamped to the amount of pending scrobbles.
+    ///
+    /// [`InvalidParameters`]: crate::error::LastFMErrorResponse::InvalidParameters
+    pub fn discard(&mut self, count: usize) -> Result<Vec<Scrobble>, Error> {
+        let count = count.min(self.pending.len());
+        let discarded = self.pending.drain(..count).collect();
+        self.rewrite()?;
+        Ok(discarded)
+    }
+
+    /// Submits the pending scrobbles in batches of at most [MAX_SCROBBLES], returning the amount of
+    /// scrobbles removed from the queue.
+    ///
+    /// Scrobbles Last.fm accepted or permanently ignored are removed from the queue. Scrobbles ignored
+    /// because the daily scrobble limit was exceeded, or which Last.fm returned no result for, are kept,
+    /// and flushing stops there.
+    /// If a batch can't be submitted, its scrobbles are kept and the error is returned. Use
+    /// [Error::is_transient] to decide whether or not flushing again later is worthwhile, and
+    /// [ScrobbleQueue::discard] to drop a batch Last.fm will never accept.
+    ///
+    /// * `client` - The client to submit the scrobbles with. It must have a session attached.
+    pub async fn flush(&mut self, client: &mut Client) -> Result<usize, Error> {
+        let mut removed = 0;
+
+        while !self.pending.is_empty() {
+            let count = self.pending.len().min(MAX_SCROBBLES);
+            let scrobbles = client.scrobble(&self.pending[..count]).await?.send().await?;
+
+            let kept = retained(&self.pending[..count], &scrobbles);
+            let limited = !kept.is_empty();
+            removed += count - kept.len();
+            self.pending.splice(..count, kept);
+            self.rewrite()?;
+
+            if limited {
+                break;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Replaces the journal with the scrobbles currently pending. The new journal is written to a
+    /// temporary file first, which then replaces the journal, so a crash never leaves it half-written.
+    fn rewrite(&self) -> Result<(), Error> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+
+        let mut journal = String::new();
+        for scrobble in &self.pending {
+            journal.push_str(&serde_json::to_string(scrobble).map_err(Error::ParsingError)?);
+            journal.push('\n');
+        }
+
+        let mut file = File::create(&temporary).map_err(Error::IOError)?;
+        file.write_all(journal.as_bytes()).map_err(Error::IOError)?;
+        file.sync_all().map_err(Error::IOError)?;
+        fs::rename(&temporary, &self.path).map_err(Error::IOError)
+    }
+}
+
+/// Returns the scrobbles of a submitted batch which have to stay in the queue, given the results
+/// Last.fm returned for the batch.
+///
+/// Scrobbles ignored because the daily scrobble limit was exceeded are retained, so they can be
+/// submitted again once the limit resets. Scrobbles without a matching result weren't processed by
+/// Last.fm, so they are retained too. Every other scrobble was either accepted or permanently ignored.
+///
+/// * `batch` - The submitted scrobbles, in the order they were submitted in.
+/// * `results` - The results Last.fm returned for the batch.
+fn retained(batch: &[Scrobble], results: &Scrobbles) -> Vec<Scrobble> {
+    batch
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| match results.scrobbles.get(*index) {
+            Some(result) => result.ignored_message.code == IgnoredCode::DailyLimitExceeded,
+            None => true,
+        })
+        .map(|(_, scrobble)| scrobble.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    /// Builds a batch of scrobbles, one per code, and the results Last.fm would return for the given
+    /// ignored message codes.
+    fn batch(codes: &[u64], results: usize) -> (Vec<Scrobble>, Scrobbles) {
+        let scrobbles: Vec<Scrobble> = (0..codes.len())
+            .map(|i| Scrobble::new("Cher", &format!("Track {}", i), Utc.timestamp_opt(1287140447 + i as i64 * 240, 0).unwrap()))
+            .collect();
+        let results: Vec<String> = codes
+            .iter()
+            .take(results)
+            .map(|code| {
+                format!(
+                    r##"{{"artist":{{"corrected":"0","#text":"Cher"}},"album":{{"corrected":"0"}},"track":{{"corrected":"0","#text":"Believe"}},"ignoredMessage":{{"code":"{}","#text":""}},"albumArtist":{{"corrected":"0","#text":""}},"timestamp":"1287140447"}}"##,
+                    code
+                )
+            })
+            .collect();
+        let body = format!(r#"{{"scrobble":[{}],"@attr":{{"ignored":0,"accepted":0}}}}"#, results.join(","));
+        (scrobbles, serde_json::from_str(&body).expect("Deserializing results"))
+    }
+
+    #[test]
+    fn retained_accepted_and_ignored() {
+        let (scrobbles, results) = batch(&[0, 1, 2, 3, 4], 5);
+        assert!(retained(&scrobbles, &results).is_empty());
+    }
+
+    #[test]
+    fn retained_daily_limit_exceeded() {
+        let (scrobbles, results) = batch(&[0, 5, 3, 5], 4);
+        assert_eq!(retained(&scrobbles, &results), vec![scrobbles[1].clone(), scrobbles[3].clone()]);
+    }
+
+    #[test]
+    fn retained_missing_results() {
+        let (scrobbles, results) = batch(&[0, 0, 0], 1);
+        assert_eq!(retained(&scrobbles, &results), scrobbles[1..].to_vec());
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::Wiki,
+    tag::Endpoints,
+    utilities::deserialize_u64,
+    Client, RequestBuilder,
+};
+
+/// The main tag info structure.
+///
+/// Contains information about a tag, such as how many users applied it and its wiki.
+#[derive(Debug, Deserialize)]
+pub struct TagInfo {
+    /// The name of the tag.
+    pub name: String,
+    /// The total amount of times the tag was applied.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub total: u64,
+    /// The amount of unique users who applied the tag.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub reach: u64,
+    /// The wiki of the tag, if it has one.
+    pub wiki: Option<Wiki>,
+}
+
+impl TagInfo {
+    pub async fn build<'a>(client: &'a mut Client, tag: &str) -> RequestBuilder<'a, TagInfo> {
+        let url = client.build_url(vec![("method", "tag.getInfo"), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TagInfo> {
+    add_param!(with_lang, lang, &str);
+
+    pub async fn send(&'a mut self) -> Result<TagInfo, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.tag.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Retrieves information about the given tag. The wiki is returned in English, unless another
+    /// language is given through the `with_lang` parameter function, using its ISO 639 alpha-2 code.
+    ///
+    /// * `tag` - The name of the tag.
+    pub async fn tag_info(&'a mut self, tag: &str) -> RequestBuilder<'a, TagInfo> {
+        TagInfo::build(self, tag).await
+    }
+}
@@ -0,0 +1,28 @@
+//! Last.fm Tag API Endpoints
+//!
+//! Contains structs and methods related to working with the tag-related endpoints
+//! available through the Last.fm API
+
+use serde::Deserialize;
+
+pub mod info;
+pub mod similar;
+pub mod top_albums;
+pub mod top_artists;
+pub mod top_tags;
+pub mod top_tracks;
+
+#[derive(Debug, Deserialize)]
+pub struct Endpoints {
+    pub tag: Option<info::TagInfo>,
+    #[serde(rename = "similartags")]
+    pub similar_tags: Option<similar::Similar>,
+    #[serde(rename = "toptags")]
+    pub top_tags: Option<top_tags::TopTags>,
+    #[serde(rename = "topartists")]
+    pub top_artists: Option<top_artists::TopArtists>,
+    #[serde(rename = "albums")]
+    pub top_albums: Option<top_albums::TopAlbums>,
+    #[serde(rename = "tracks")]
+    pub top_tracks: Option<top_tracks::TopTracks>,
+}
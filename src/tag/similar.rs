@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{custom_deserialization::one_or_many, Tag},
+    tag::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main similar structure.
+///
+/// This structure only has one component to it: the tags that are similar to the
+/// one provided. For details on the information available for the tags, refer to
+/// the [Tag] struct.
+#[derive(Debug, Deserialize)]
+pub struct Similar {
+    /// A [Vec] containing similar [Tag]s. Empty if Last.fm knows no similar tags.
+    #[serde(rename = "tag", default, deserialize_with = "one_or_many")]
+    pub tags: Vec<Tag>,
+}
+
+impl Similar {
+    pub async fn build<'a>(client: &'a mut Client, tag: &str) -> RequestBuilder<'a, Similar> {
+        let url = client.build_url(vec![("method", "tag.getSimilar"), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, Similar> {
+    pub async fn send(&'a mut self) -> Result<Similar, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.similar_tags.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn similar_tags(&'a mut self, tag: &str) -> RequestBuilder<'a, Similar> {
+        Similar::build(self, tag).await
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{Album, Attributes},
+    tag::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main top albums structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the tag and pagination attributes), and two,
+/// a list of the most popular albums tagged with the tag.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the album information available, refer to [Album].
+#[derive(Debug, Deserialize)]
+pub struct TopAlbums {
+    /// The attributes associated with the tag's Top Albums listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular albums tagged with the tag.
+    #[serde(rename = "album")]
+    pub albums: Vec<Album>,
+}
+
+impl TopAlbums {
+    pub async fn build<'a>(client: &'a mut Client, tag: &str) -> RequestBuilder<'a, TopAlbums> {
+        let url = client.build_url(vec![("method", "tag.getTopAlbums"), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopAlbums> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopAlbums, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.top_albums.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn tag_top_albums(&'a mut self, tag: &str) -> RequestBuilder<'a, TopAlbums> {
+        TopAlbums::build(self, tag).await
+    }
+}
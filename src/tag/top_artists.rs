@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{Artist, Attributes},
+    tag::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main top artists structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the tag and pagination attributes), and two,
+/// a list of the most popular artists tagged with the tag.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the artist information available, refer to [Artist].
+#[derive(Debug, Deserialize)]
+pub struct TopArtists {
+    /// The attributes associated with the tag's Top Artists listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular artists tagged with the tag.
+    #[serde(rename = "artist")]
+    pub artists: Vec<Artist>,
+}
+
+impl TopArtists {
+    pub async fn build<'a>(client: &'a mut Client, tag: &str) -> RequestBuilder<'a, TopArtists> {
+        let url = client.build_url(vec![("method", "tag.getTopArtists"), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopArtists> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopArtists, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.top_artists.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn tag_top_artists(&'a mut self, tag: &str) -> RequestBuilder<'a, TopArtists> {
+        TopArtists::build(self, tag).await
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::Tag,
+    tag::Endpoints,
+    utilities::deserialize_u64,
+    Client, RequestBuilder,
+};
+
+/// The main top tags structure.
+///
+/// This is splitted off into two areas: One, the attributes (used for paginating
+/// through the listing), and two, the tags applied most across Last.fm.
+///
+/// For details on the tag information available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct TopTags {
+    /// The attributes associated with the Top Tags listing.
+    #[serde(rename = "@attr")]
+    pub attrs: TopTagsAttributes,
+    /// A [Vec] containing the most applied tags.
+    #[serde(rename = "tag")]
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TopTagsAttributes {
+    /// The index of the first tag in the listing.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub offset: u64,
+    /// The amount of tags in the listing.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub num_res: u64,
+    /// The total amount of tags available.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub total: u64,
+}
+
+impl TopTags {
+    pub async fn build<'a>(client: &'a mut Client) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "tag.getTopTags")]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTags> {
+    add_param!(with_limit, num_res, usize);
+    add_param!(with_offset, offset, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopTags, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.top_tags.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Retrieves the tags applied most across Last.fm, ordered by their total amount of taggings.
+    pub async fn tag_top_tags(&'a mut self) -> RequestBuilder<'a, TopTags> {
+        TopTags::build(self).await
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{Attributes, Track},
+    tag::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main top tracks structure.
+///
+/// This is splitted off into two areas: One, the attributes (used
+/// for displaying the tag and pagination attributes), and two,
+/// a list of the most popular tracks tagged with the tag.
+///
+/// For details on the attributes available, refer to [Attributes]. For
+/// details on the track information available, refer to [Track].
+#[derive(Debug, Deserialize)]
+pub struct TopTracks {
+    /// The attributes associated with the tag's Top Tracks listing.
+    #[serde(rename = "@attr")]
+    pub attrs: Attributes,
+    /// A [Vec] containing the most popular tracks tagged with the tag.
+    #[serde(rename = "track")]
+    pub tracks: Vec<Track>,
+}
+
+impl TopTracks {
+    pub async fn build<'a>(client: &'a mut Client, tag: &str) -> RequestBuilder<'a, TopTracks> {
+        let url = client.build_url(vec![("method", "tag.getTopTracks"), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TopTracks> {
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TopTracks, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tags) => Ok(tags.top_tracks.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn tag_top_tracks(&'a mut self, tag: &str) -> RequestBuilder<'a, TopTracks> {
+        TopTracks::build(self, tag).await
+    }
+}
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::Artist,
+    track::Endpoints,
+    utilities::deserialize_bool,
+    Client, RequestBuilder,
+};
+
+/// The main track correction structure.
+///
+/// Contains the canonical track Last.fm would correct a submission of the given track to,
+/// alongside whether or not the artist and track names were corrected.
+#[derive(Debug, Deserialize)]
+pub struct TrackCorrection {
+    /// The canonical track.
+    pub track: CorrectedTrack,
+    /// Which parts of the submission were corrected.
+    #[serde(rename = "@attr")]
+    pub attrs: TrackCorrectionAttributes,
+}
+
+/// The canonical version of a track, as stored by Last.fm.
+#[derive(Debug, Deserialize)]
+pub struct CorrectedTrack {
+    /// The canonical name of the track.
+    pub name: String,
+    /// The MusicBrainz ID of the track.
+    pub mbid: Option<String>,
+    /// The Last.fm URL of the track.
+    pub url: String,
+    /// The canonical artist of the track.
+    pub artist: Artist,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrackCorrectionAttributes {
+    /// Whether or not the artist name was corrected.
+    #[serde(rename = "artistcorrected", deserialize_with = "deserialize_bool")]
+    pub artist_corrected: bool,
+    /// Whether or not the track name was corrected.
+    #[serde(rename = "trackcorrected", deserialize_with = "deserialize_bool")]
+    pub track_corrected: bool,
+}
+
+impl TrackCorrection {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, TrackCorrection> {
+        let url = client.build_url(vec![("method", "track.getCorrection"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TrackCorrection> {
+    /// Sends the request, returning [None] if Last.fm doesn't know of the given track.
+    pub async fn send(&'a mut self) -> Result<Option<TrackCorrection>, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tracks) => Ok(tracks.correction),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Looks up the canonical track Last.fm would correct the given track to.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn track_correction(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, TrackCorrection> {
+        TrackCorrection::build(self, artist, track).await
+    }
+}
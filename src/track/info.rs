@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{custom_deserialization::tag_list, Artist, Image, Tag, Wiki},
+    track::Endpoints,
+    utilities::{deserialize_option_bool, deserialize_option_u64, deserialize_u64},
+    Client, RequestBuilder,
+};
+
+/// The main track info structure.
+///
+/// Contains detailed information about a track, such as its listener count and wiki. When
+/// the request is made for a given user, the user's play count of the track and whether or not
+/// the user loved the track are available as well.
+#[derive(Debug, Deserialize)]
+pub struct TrackInfo {
+    /// The name of the track.
+    pub name: String,
+    /// The MusicBrainz ID of the track.
+    pub mbid: Option<String>,
+    /// The Last.fm URL of the track.
+    pub url: String,
+    /// The length of the track, in milliseconds. [None] if unknown to Last.fm.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub duration: Option<u64>,
+    /// The amount of unique listeners of the track.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub listeners: u64,
+    /// The amount of times the track has been played across Last.fm.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub playcount: u64,
+    /// The artist who performed the track.
+    pub artist: Artist,
+    /// The album the track appears on, if known.
+    pub album: Option<TrackAlbum>,
+    /// The most popular tags applied to the track.
+    #[serde(rename = "toptags", default, deserialize_with = "tag_list")]
+    pub tags: Vec<Tag>,
+    /// The wiki of the track, if it has one.
+    pub wiki: Option<Wiki>,
+    /// The amount of times the given user has played the track.
+    #[serde(rename = "userplaycount", default, deserialize_with = "deserialize_option_u64")]
+    pub user_playcount: Option<u64>,
+    /// Whether or not the given user loved the track.
+    #[serde(rename = "userloved", default, deserialize_with = "deserialize_option_bool")]
+    pub user_loved: Option<bool>,
+}
+
+/// The album a track appears on.
+#[derive(Debug, Deserialize)]
+pub struct TrackAlbum {
+    /// The name of the album's artist.
+    pub artist: String,
+    /// The name of the album.
+    pub title: String,
+    /// The MusicBrainz ID of the album.
+    pub mbid: Option<String>,
+    /// The Last.fm URL of the album.
+    pub url: String,
+    /// The album's cover art, in multiple sizes.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+    /// Attributes associated with the album.
+    #[serde(rename = "@attr")]
+    pub attrs: Option<TrackAlbumAttributes>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrackAlbumAttributes {
+    /// The position of the track on the album.
+    #[serde(default, deserialize_with = "deserialize_option_u64")]
+    pub position: Option<u64>,
+}
+
+impl TrackInfo {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, TrackInfo> {
+        let url = client.build_url(vec![("method", "track.getInfo"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, TrackInfo> {
+        let url = client.build_url(vec![("method", "track.getInfo"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TrackInfo> {
+    add_param!(with_username, username, &str);
+    add_flag!(with_autocorrect, autocorrect);
+
+    pub async fn send(&'a mut self) -> Result<TrackInfo, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tracks) => Ok(tracks.track.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    pub async fn track_info_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, TrackInfo> {
+        TrackInfo::build_by_mbid(self, mbid).await
+    }
+
+    pub async fn track_info(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, TrackInfo> {
+        TrackInfo::build(self, artist, track).await
+    }
+}
@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    Client, RequestBuilder,
+};
+
+/// Marks a track as loved by the authenticated user. The counterpart of [Unlove].
+///
+/// Last.fm doesn't return any data for this method, so sending the request only reports
+/// whether or not it succeeded.
+#[derive(Debug)]
+pub struct Love;
+
+/// Removes a track from the authenticated user's loved tracks. The counterpart of [Love].
+#[derive(Debug)]
+pub struct Unlove;
+
+impl Love {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, Love> {
+        let url = client.build_url(vec![("method", "track.love"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl Unlove {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, Unlove> {
+        let url = client.build_url(vec![("method", "track.unlove"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, Love> {
+    pub async fn send(&'a mut self) -> Result<(), Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl<'a> RequestBuilder<'a, Unlove> {
+    pub async fn send(&'a mut self) -> Result<(), Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Marks the given track as loved by the authenticated user.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn love_track(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, Love> {
+        Love::build(self, artist, track).await
+    }
+
+    /// Removes the given track from the authenticated user's loved tracks.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn unlove_track(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, Unlove> {
+        Unlove::build(self, artist, track).await
+    }
+}
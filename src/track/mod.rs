@@ -3,12 +3,31 @@
 //! Contains structs and methods related to working with the track-related endpoints
 //! available through the Last.fm API
 
+use crate::model::custom_deserialization::correction as deserialize_correction;
 use serde::Deserialize;
 
+pub mod correction;
+pub mod info;
+pub mod love;
+pub mod now_playing;
+pub mod scrobble;
+pub mod search;
 pub mod similar;
+pub mod tags;
 
 #[derive(Debug, Deserialize)]
 pub struct Endpoints {
     #[serde(rename = "similartracks")]
     pub similar_tracks: Option<similar::Similar>,
+    pub scrobbles: Option<scrobble::Scrobbles>,
+    pub track: Option<info::TrackInfo>,
+    pub tags: Option<tags::Tags>,
+    #[serde(rename = "toptags")]
+    pub top_tags: Option<tags::TopTags>,
+    #[serde(rename = "corrections", default, deserialize_with = "deserialize_correction")]
+    pub correction: Option<correction::TrackCorrection>,
+    #[serde(rename = "results")]
+    pub search: Option<search::TrackSearch>,
+    #[serde(rename = "nowplaying")]
+    pub now_playing: Option<now_playing::NowPlaying>,
 }
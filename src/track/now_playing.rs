@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{Corrected, IgnoredMessage},
+    track::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main now playing structure.
+///
+/// Contains the track the authenticated user is now listening to, as stored by Last.fm after
+/// any corrections, alongside whether or not the update was ignored.
+#[derive(Debug, Deserialize)]
+pub struct NowPlaying {
+    /// The artist of the track, as stored by Last.fm.
+    pub artist: Corrected,
+    /// The name of the track, as stored by Last.fm.
+    pub track: Corrected,
+    /// The album of the track, as stored by Last.fm.
+    pub album: Corrected,
+    /// The album artist of the track, as stored by Last.fm.
+    #[serde(rename = "albumArtist")]
+    pub album_artist: Corrected,
+    /// Whether or not, and why, the update was ignored.
+    #[serde(rename = "ignoredMessage")]
+    pub ignored_message: IgnoredMessage,
+}
+
+impl NowPlaying {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, NowPlaying> {
+        let url = client.build_url(vec![("method", "track.updateNowPlaying"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, NowPlaying> {
+    add_param!(with_album, album, &str);
+    add_param!(with_album_artist, albumArtist, &str);
+    add_param!(with_track_number, trackNumber, u32);
+    add_param!(with_duration, duration, u32);
+    add_param!(with_mbid, mbid, &str);
+
+    pub async fn send(&'a mut self) -> Result<NowPlaying, Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                Ok(tracks) => Ok(tracks.now_playing.unwrap()),
+                Err(e) => Err(Error::ParsingError(e)),
+            },
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Notifies Last.fm that the authenticated user has started listening to the given track.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn update_now_playing(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, NowPlaying> {
+        NowPlaying::build(self, artist, track).await
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{custom_deserialization::one_or_many, Corrected, IgnoredMessage},
+    track::Endpoints,
+    utilities::{deserialize_datetime_from_str, deserialize_u64},
+    Client, RequestBuilder,
+};
+
+/// The maximum amount of scrobbles the Last.fm API accepts in a single request.
+pub const MAX_SCROBBLES: usize = 50;
+
+/// A track play to submit to Last.fm.
+///
+/// Only the artist, track and timestamp are required, the remaining fields are optional
+/// and can be set through struct update syntax, e.g.
+/// `Scrobble { album: Some("Takk...".to_owned()), ..Scrobble::new("Sigur Rós", "Hoppípolla", timestamp) }`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Scrobble {
+    /// The name of the artist who performed the track.
+    pub artist: String,
+    /// The name of the track.
+    pub track: String,
+    /// The time the track started playing, in UTC.
+    pub timestamp: DateTime<Utc>,
+    /// The name of the album the track appears on.
+    pub album: Option<String>,
+    /// The album artist, if it differs from the track artist.
+    pub album_artist: Option<String>,
+    /// The position of the track on the album.
+    pub track_number: Option<u32>,
+    /// The length of the track, in seconds.
+    pub duration: Option<u32>,
+    /// The MusicBrainz ID of the track.
+    pub mbid: Option<String>,
+    /// Whether or not the user chose to play the track, as opposed to e.g. a radio station or a
+    /// recommendation service choosing it for them.
+    pub chosen_by_user: Option<bool>,
+}
+
+impl Scrobble {
+    /// Creates a new scrobble with none of the optional fields set.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    /// * `timestamp` - The time the track started playing.
+    pub fn new(artist: &str, track: &str, timestamp: DateTime<Utc>) -> Scrobble {
+        Scrobble {
+            artist: artist.to_owned(),
+            track: track.to_owned(),
+            timestamp,
+            album: None,
+            album_artist: None,
+            track_number: None,
+            duration: None,
+            mbid: None,
+            chosen_by_user: None,
+        }
+    }
+
+    /// Converts the scrobble to its request parameters, using the given index for batch submissions.
+    fn params(&self, index: usize) -> Vec<(String, String)> {
+        let mut params = vec![
+            (format!("artist[{}]", index), self.artist.clone()),
+            (format!("track[{}]", index), self.track.clone()),
+            (format!("timestamp[{}]", index), self.timestamp.timestamp().to_string()),
+        ];
+        if let Some(album) = &self.album {
+            params.push((format!("album[{}]", index), album.clone()));
+        }
+        if let Some(album_artist) = &self.album_artist {
+            params.push((format!("albumArtist[{}]", index), album_artist.clone()));
+        }
+        if let Some(track_number) = self.track_number {
+            params.push((format!("trackNumber[{}]", index), track_number.to_string()));
+        }
+        if let Some(duration) = self.duration {
+            params.push((format!("duration[{}]", index), duration.to_string()));
+        }
+        if let Some(mbid) = &self.mbid {
+            params.push((format!("mbid[{}]", index), mbid.clone()));
+        }
+        if let Some(chosen_by_user) = self.chosen_by_user {
+            params.push((format!("chosenByUser[{}]", index), (chosen_by_user as u8).to_string()));
+        }
+        params
+    }
+}
+
+/// The main scrobbles structure.
+///
+/// This is splitted off into two areas: One, the attributes (containing the amount of scrobbles
+/// accepted and ignored), and two, the results for each submitted scrobble, in the order they were
+/// submitted in.
+#[derive(Debug, Deserialize)]
+pub struct Scrobbles {
+    /// The amount of scrobbles accepted and ignored.
+    #[serde(rename = "@attr")]
+    pub attrs: ScrobblesAttributes,
+    /// A [Vec] containing the result of each submitted scrobble.
+    #[serde(rename = "scrobble", deserialize_with = "one_or_many")]
+    pub scrobbles: Vec<ScrobbleResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScrobblesAttributes {
+    /// The amount of scrobbles Last.fm accepted.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub accepted: u64,
+    /// The amount of scrobbles Last.fm ignored.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub ignored: u64,
+}
+
+/// The result of a single submitted scrobble.
+#[derive(Debug, Deserialize)]
+pub struct ScrobbleResult {
+    /// The artist of the scrobble, as stored by Last.fm.
+    pub artist: Corrected,
+    /// The track of the scrobble, as stored by Last.fm.
+    pub track: Corrected,
+    /// The album of the scrobble, as stored by Last.fm.
+    pub album: Corrected,
+    /// The album artist of the scrobble, as stored by Last.fm.
+    #[serde(rename = "albumArtist")]
+    pub album_artist: Corrected,
+    /// The time the scrobbled track started playing.
+    #[serde(deserialize_with = "deserialize_datetime_from_str")]
+    pub timestamp: DateTime<Utc>,
+    /// Whether or not, and why, the scrobble was ignored.
+    #[serde(rename = "ignoredMessage")]
+    pub ignored_message: IgnoredMessage,
+}
+
+impl Scrobbles {
+    pub async fn build<'a>(client: &'a mut Client, scrobbles: &[Scrobble]) -> Result<RequestBuilder<'a, Scrobbles>, Error> {
+        if scrobbles.is_empty() {
+            return Err(Error::NoScrobbles);
+        }
+        if scrobbles.len() > MAX_SCROBBLES {
+            return Err(Error::TooManyScrobbles(scrobbles.len()));
+        }
+
+        let params: Vec<(String, String)> = scrobbles.iter().enumerate().flat_map(|(index, scrobble)| scrobble.params(index)).collect();
+        let mut params: Vec<(&str, &str)> = params.iter().map(|(key, value)| (key.as_str(), value.as_str())).collect();
+        params.insert(0, ("method", "track.scrobble"));
+
+        let url = client.build_url(params).await;
+        Ok(RequestBuilder { client, url, phantom: PhantomData })
+    }
+}
+
+impl<'a> RequestBuilder<'a, Scrobbles> {
+    pub async fn send(&'a mut self) -> Result<Scrobbles, Error> {
+        let body = self.client.signed_request(&self.url).await?;
+        match serde_json::from_str::<LastFMError>(&body) {
+            Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+            Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                Ok(tracks) => Ok(tracks.scrobbles.unwrap()),
+                Err(e) => Err(Error::ParsingError(e)),
+            },
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Submits one or more track plays to the authenticated user's profile. Up to [MAX_SCROBBLES]
+    /// scrobbles can be submitted at once; an empty or oversized batch is rejected with
+    /// [Error::NoScrobbles] or [Error::TooManyScrobbles] before anything is sent.
+    ///
+    /// * `scrobbles` - The track plays to submit.
+    pub async fn scrobble(&'a mut self, scrobbles: &[Scrobble]) -> Result<RequestBuilder<'a, Scrobbles>, Error> {
+        Scrobbles::build(self, scrobbles).await
+    }
+}
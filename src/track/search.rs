@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{Image, OpenSearch},
+    track::Endpoints,
+    utilities::deserialize_u64,
+    Client, RequestBuilder,
+};
+
+/// The main track search structure.
+///
+/// This is splitted off into two areas: One, the OpenSearch metadata (used for paginating
+/// through the results), and two, the tracks matching the search.
+///
+/// For details on the metadata available, refer to [OpenSearch]. For details on the track
+/// information available, refer to [TrackMatch].
+#[derive(Debug, Deserialize)]
+pub struct TrackSearch {
+    /// The OpenSearch metadata of the search.
+    #[serde(flatten)]
+    pub opensearch: OpenSearch,
+    /// The tracks matching the search.
+    #[serde(rename = "trackmatches")]
+    pub matches: TrackMatches,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrackMatches {
+    /// A [Vec] containing the tracks matching the search, best matches first.
+    #[serde(rename = "track")]
+    pub tracks: Vec<TrackMatch>,
+}
+
+/// A track matching a search.
+#[derive(Debug, Deserialize)]
+pub struct TrackMatch {
+    /// The name of the track.
+    pub name: String,
+    /// The name of the artist who performed the track.
+    pub artist: String,
+    /// The Last.fm URL of the track.
+    pub url: String,
+    /// The amount of unique listeners of the track.
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub listeners: u64,
+    /// The MusicBrainz ID of the track.
+    pub mbid: Option<String>,
+    /// Images associated with the track.
+    #[serde(rename = "image", default)]
+    pub images: Vec<Image>,
+}
+
+impl TrackSearch {
+    pub async fn build<'a>(client: &'a mut Client, track: &str) -> RequestBuilder<'a, TrackSearch> {
+        let url = client.build_url(vec![("method", "track.search"), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl<'a> RequestBuilder<'a, TrackSearch> {
+    add_param!(with_artist, artist, &str);
+    add_param!(with_limit, limit, usize);
+    add_param!(with_page, page, usize);
+
+    pub async fn send(&'a mut self) -> Result<TrackSearch, Error> {
+        match self.client.request(&self.url).await {
+            Ok(response) => {
+                let body = response.text().await.unwrap();
+                match serde_json::from_str::<LastFMError>(&body) {
+                    Ok(lastfm_error) => Err(Error::LastFMError(lastfm_error.into())),
+                    Err(_) => match serde_json::from_str::<Endpoints>(&body) {
+                        Ok(tracks) => Ok(tracks.search.unwrap()),
+                        Err(e) => Err(Error::ParsingError(e)),
+                    },
+                }
+            }
+            Err(err) => Err(Error::HTTPError(err)),
+        }
+    }
+}
+
+impl<'a> Client {
+    /// Searches for tracks by name. The search can be narrowed down to a given artist through
+    /// the `with_artist` parameter function.
+    ///
+    /// * `track` - The name of the track to search for.
+    pub async fn search_tracks(&'a mut self, track: &str) -> RequestBuilder<'a, TrackSearch> {
+        TrackSearch::build(self, track).await
+    }
+}
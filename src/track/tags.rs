@@ -0,0 +1,150 @@
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::{
+    error::{Error, LastFMError},
+    model::{custom_deserialization::one_or_many, Tag, MAX_TAGS},
+    track::Endpoints,
+    Client, RequestBuilder,
+};
+
+/// The main tags structure.
+///
+/// Contains the tags a user applied to the given track. For details on the tag information
+/// available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct Tags {
+    /// A [Vec] containing the tags the user applied to the track.
+    #[serde(rename = "tag", default, deserialize_with = "one_or_many")]
+    pub tags: Vec<Tag>,
+    /// The track the tags were applied to.
+    #[serde(rename = "@attr")]
+    pub attrs: TagsAttributes,
+}
+
+/// The main top tags structure.
+///
+/// Contains the tags applied most to the given track across Last.fm, alongside their counts. For
+/// details on the tag information available, refer to [Tag].
+#[derive(Debug, Deserialize)]
+pub struct TopTags {
+    /// A [Vec] containing the tags applied most to the track, most applied first.
+    #[serde(rename = "tag", default, deserialize_with = "one_or_many")]
+    pub tags: Vec<Tag>,
+    /// The track the tags were applied to.
+    #[serde(rename = "@attr")]
+    pub attrs: TagsAttributes,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagsAttributes {
+    /// The name of the artist who performed the track.
+    pub artist: String,
+    /// The name of the track.
+    pub track: String,
+}
+
+/// Applies tags to a track on behalf of the authenticated user. Up to [MAX_TAGS] tags can be applied
+/// at once.
+///
+/// Last.fm doesn't return any data for this method, so sending the request only reports
+/// whether or not it succeeded.
+#[derive(Debug)]
+pub struct AddTags;
+
+/// Removes a tag the authenticated user applied to a track.
+#[derive(Debug)]
+pub struct RemoveTag;
+
+impl Tags {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, Tags> {
+        let url = client.build_url(vec![("method", "track.getTags"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, Tags> {
+        let url = client.build_url(vec![("method", "track.getTags"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl TopTags {
+    pub async fn build_by_mbid<'a>(client: &'a mut Client, mbid: &str) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "track.getTopTags"), ("mbid", mbid)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str) -> RequestBuilder<'a, TopTags> {
+        let url = client.build_url(vec![("method", "track.getTopTags"), ("artist", artist), ("track", track)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+impl AddTags {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str, tags: &[&str]) -> Result<RequestBuilder<'a, AddTags>, Error> {
+        if tags.is_empty() {
+            return Err(Error::NoTags);
+        }
+        if tags.len() > MAX_TAGS {
+            return Err(Error::TooManyTags(tags.len()));
+        }
+
+        let tags = tags.join(",");
+        let url = client.build_url(vec![("method", "track.addTags"), ("artist", artist), ("track", track), ("tags", &tags)]).await;
+        Ok(RequestBuilder { client, url, phantom: PhantomData })
+    }
+}
+
+impl RemoveTag {
+    pub async fn build<'a>(client: &'a mut Client, artist: &str, track: &str, tag: &str) -> RequestBuilder<'a, RemoveTag> {
+        let url = client.build_url(vec![("method", "track.removeTag"), ("artist", artist), ("track", track), ("tag", tag)]).await;
+        RequestBuilder { client, url, phantom: PhantomData }
+    }
+}
+
+tag_requests!();
+
+impl<'a> Client {
+    pub async fn track_tags_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, Tags> {
+        Tags::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves the tags a user applied to the given track.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn track_tags(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, Tags> {
+        Tags::build(self, artist, track).await
+    }
+
+    pub async fn track_top_tags_by_mbid(&'a mut self, mbid: &str) -> RequestBuilder<'a, TopTags> {
+        TopTags::build_by_mbid(self, mbid).await
+    }
+
+    /// Retrieves the tags applied most to the given track across Last.fm.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    pub async fn track_top_tags(&'a mut self, artist: &str, track: &str) -> RequestBuilder<'a, TopTags> {
+        TopTags::build(self, artist, track).await
+    }
+
+    /// Applies the given tags to the track on behalf of the authenticated user. Fails with
+    /// [Error::NoTags] if no tags are given, or [Error::TooManyTags] if more than [MAX_TAGS] are given.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    /// * `tags` - The tags to apply.
+    pub async fn add_track_tags(&'a mut self, artist: &str, track: &str, tags: &[&str]) -> Result<RequestBuilder<'a, AddTags>, Error> {
+        AddTags::build(self, artist, track, tags).await
+    }
+
+    /// Removes a tag the authenticated user applied to the track.
+    ///
+    /// * `artist` - The name of the artist who performed the track.
+    /// * `track` - The name of the track.
+    /// * `tag` - The tag to remove.
+    pub async fn remove_track_tag(&'a mut self, artist: &str, track: &str, tag: &str) -> RequestBuilder<'a, RemoveTag> {
+        RemoveTag::build(self, artist, track, tag).await
+    }
+}
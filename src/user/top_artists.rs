@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::marker::PhantomData;
 
 use crate::{
@@ -56,18 +57,18 @@ pub enum Period {
     Overall,
 }
 
-impl ToString for Period {
+impl Display for Period {
     /// Converts the given period to a string. In most cases, you won't have to use this
     /// yourself. Period durations will usually be automatically converted to their string
     /// form when fed to the `with_period` parameter function.
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
-            Self::Overall => String::from("overall"),
-            Self::SevenDays => String::from("7day"),
-            Self::OneMonth => String::from("1month"),
-            Self::ThreeMonths => String::from("3month"),
-            Self::SixMonths => String::from("6month"),
-            Self::TwelveMonths | Self::OneYear => String::from("12month"),
+            Self::Overall => write!(f, "overall"),
+            Self::SevenDays => write!(f, "7day"),
+            Self::OneMonth => write!(f, "1month"),
+            Self::ThreeMonths => write!(f, "3month"),
+            Self::SixMonths => write!(f, "6month"),
+            Self::TwelveMonths | Self::OneYear => write!(f, "12month"),
         }
     }
 }
@@ -49,9 +49,9 @@ impl<'a> RequestBuilder<'a, TopTracks> {
         match self.client.request(&self.url).await {
             Ok(response) => {
                 let body = response.text().await.unwrap();
-                match serde_json::from_str::<LastFMError>(&*body) {
+                match serde_json::from_str::<LastFMError>(&body) {
                     Ok(lastm_error) => Err(Error::LastFMError(lastm_error.into())),
-                    Err(_) => match serde_json::from_str::<User>(&*body) {
+                    Err(_) => match serde_json::from_str::<User>(&body) {
                         Ok(user) => Ok(user.top_tracks.unwrap()),
                         Err(e) => Err(Error::ParsingError(e)),
                     },
@@ -1,6 +1,6 @@
 //! Miscellaneous utilities.
 
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer};
 
 /// Deserializes a given [`str`] into a format readable by the [`DateTime`] and [`Utc`]
@@ -11,6 +11,141 @@ where
 {
     let date: String = Deserialize::deserialize(deserializer)?;
     let timestamp = date.parse::<i64>().map_err(de::Error::custom)?;
-    let naive_datetime = NaiveDateTime::from_timestamp(timestamp, 0);
-    Ok(DateTime::from_utc(naive_datetime, Utc))
+    DateTime::from_timestamp(timestamp, 0).ok_or_else(|| de::Error::custom("timestamp out of range"))
+}
+
+/// A boolean flag, as transmitted by the Last.fm API.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Flag {
+    Bool(bool),
+    Integer(u64),
+    String(String),
+}
+
+impl Flag {
+    fn into_bool<E: de::Error>(self) -> Result<bool, E> {
+        match self {
+            Flag::Bool(flag) => Ok(flag),
+            Flag::Integer(flag) => Ok(flag != 0),
+            Flag::String(flag) => match flag.as_str() {
+                "1" | "true" => Ok(true),
+                "0" | "false" | "" => Ok(false),
+                _ => Err(de::Error::custom(format!("invalid boolean flag: {}", flag))),
+            },
+        }
+    }
+}
+
+/// An unsigned integer, as transmitted by the Last.fm API.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Number {
+    Integer(u64),
+    String(String),
+}
+
+impl Number {
+    fn into_u64<E: de::Error>(self) -> Result<Option<u64>, E> {
+        match self {
+            Number::Integer(number) => Ok(Some(number)),
+            Number::String(number) if number.trim().is_empty() => Ok(None),
+            Number::String(number) => number.trim().parse::<u64>().map(Some).map_err(de::Error::custom),
+        }
+    }
+}
+
+/// A decimal number, as transmitted by the Last.fm API.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Decimal {
+    Float(f32),
+    String(String),
+}
+
+impl Decimal {
+    fn into_f32<E: de::Error>(self) -> Result<Option<f32>, E> {
+        match self {
+            Decimal::Float(number) => Ok(Some(number)),
+            Decimal::String(number) if number.trim().is_empty() => Ok(None),
+            Decimal::String(number) => number.trim().parse::<f32>().map(Some).map_err(de::Error::custom),
+        }
+    }
+}
+
+/// Deserializes a boolean flag transmitted by the Last.fm API. Flags are transmitted as either
+/// integers or strings containing `0` or `1`, depending on the endpoint.
+pub fn deserialize_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Flag::deserialize(deserializer)?.into_bool()
+}
+
+/// Deserializes an optional boolean flag transmitted by the Last.fm API. Use alongside
+/// `#[serde(default)]` for flags that are only transmitted in certain cases.
+pub fn deserialize_option_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Flag>::deserialize(deserializer)?.map(Flag::into_bool).transpose()
+}
+
+/// Deserializes an unsigned integer transmitted by the Last.fm API. Depending on the endpoint,
+/// numbers are transmitted as either JSON numbers or strings.
+pub fn deserialize_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Number::deserialize(deserializer)?
+        .into_u64()?
+        .ok_or_else(|| de::Error::custom("expected a number, found an empty string"))
+}
+
+/// Deserializes an optional unsigned integer transmitted by the Last.fm API. Null values and empty
+/// strings are treated as missing. Use alongside `#[serde(default)]` for numbers that are only
+/// transmitted in certain cases.
+pub fn deserialize_option_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Number>::deserialize(deserializer)? {
+        Some(number) => number.into_u64(),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes an optional decimal number transmitted by the Last.fm API, such as the similarity
+/// score of an item. Null values and empty strings are treated as missing. Use alongside
+/// `#[serde(default)]`.
+pub fn deserialize_option_f32<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Decimal>::deserialize(deserializer)? {
+        Some(number) => number.into_f32(),
+        None => Ok(None),
+    }
+}
+
+/// Computes the `api_sig` parameter used to sign requests to the Last.fm API.
+///
+/// Every parameter except `format` and `callback` is sorted alphabetically by name and
+/// concatenated as `<name><value>`, the API shared secret is appended to the end, and the
+/// resulting string is hashed with MD5. The hash is returned as a lowercase hexadecimal string.
+///
+/// * `params` - The parameters of the request to sign, including `method` and `api_key`.
+/// * `secret` - The API shared secret associated with the API key.
+pub fn sign(params: &[(String, String)], secret: &str) -> String {
+    let mut params: Vec<&(String, String)> = params.iter().filter(|(key, _)| key != "format" && key != "callback").collect();
+    params.sort();
+
+    let mut signature = String::new();
+    for (key, value) in params {
+        signature.push_str(key);
+        signature.push_str(value);
+    }
+    signature.push_str(secret);
+
+    format!("{:x}", md5::compute(signature.as_bytes()))
 }
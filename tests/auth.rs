@@ -0,0 +1,125 @@
+mod common;
+
+use common::now;
+use lastfm_rs::auth::session::Session;
+use lastfm_rs::auth::web::callback_token;
+use lastfm_rs::error::{LastFMError, LastFMErrorResponse};
+use lastfm_rs::Client;
+
+#[test]
+fn callback_token_from_absolute_url() {
+    let token = callback_token("https://example.com/lastfm/callback?state=1&token=cf45fe5a3e3cebe168480a086d7fe481");
+    assert_eq!(token.as_deref(), Some("cf45fe5a3e3cebe168480a086d7fe481"));
+}
+
+#[test]
+fn callback_token_from_request_target() {
+    let token = callback_token("/lastfm/callback?token=cf45fe5a3e3cebe168480a086d7fe481");
+    assert_eq!(token.as_deref(), Some("cf45fe5a3e3cebe168480a086d7fe481"));
+}
+
+#[test]
+fn callback_token_missing() {
+    assert_eq!(callback_token("/lastfm/callback?state=1"), None);
+    assert_eq!(callback_token("/lastfm/callback?token="), None);
+}
+
+#[test]
+fn web_auth_url() {
+    let client = Client::new("xxxxxxxxxx");
+    let url = client.web_auth_url("https://example.com/lastfm/callback?state=1");
+    assert_eq!(
+        url.as_str(),
+        "https://www.last.fm/api/auth/?api_key=xxxxxxxxxx&cb=https%3A%2F%2Fexample.com%2Flastfm%2Fcallback%3Fstate%3D1"
+    );
+}
+
+#[test]
+fn authentication_failed_error() {
+    let body = r#"{"message":"Authentication Failed - You do not have permissions to access the service","error":4,"links":[]}"#;
+    let error = serde_json::from_str::<LastFMError>(body).expect("Deserializing error");
+    assert!(matches!(LastFMErrorResponse::from(error), LastFMErrorResponse::AuthenticationFailed(_)));
+}
+
+#[test]
+fn session_save_and_load() {
+    let path = std::env::temp_dir().join(format!("lastfm-rs-session-{}.json", std::process::id()));
+    let session = Session {
+        name: "tzuwy".to_owned(),
+        key: "d580d57f32848f5dcf574d1ce18d78b2".to_owned(),
+        subscriber: true,
+    };
+
+    session.save(&path).expect("Saving session");
+    let loaded = Session::load(&path).expect("Loading session");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).expect("Reading session metadata").permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    std::fs::remove_file(&path).expect("Removing session file");
+    assert_eq!(loaded, session);
+}
+
+#[test]
+fn session_hook_accepts_async_closures() {
+    let path = std::env::temp_dir().join("lastfm-rs-session.json");
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    client.on_invalid_session(move || {
+        let path = path.clone();
+        async move { Session::load(path).ok() }
+    });
+    assert!(client.session().is_none());
+}
+
+#[test]
+fn invalid_session_detection() {
+    let invalid = r#"{"message":"Invalid session key - Please re-authenticate","error":9,"links":[]}"#;
+    let failed = r#"{"message":"Operation failed - Most likely the backend service failed. Please try again.","error":8,"links":[]}"#;
+    let success = r##"{"nowplaying":{"ignoredMessage":{"code":"0","#text":""}}}"##;
+
+    assert!(LastFMError::is_invalid_session(invalid));
+    assert!(!LastFMError::is_invalid_session(failed));
+    assert!(!LastFMError::is_invalid_session(success));
+}
+
+#[test]
+fn session_hook_renews_session() {
+    let renewed = Session {
+        name: "tzuwy".to_owned(),
+        key: "0123456789abcdef0123456789abcdef".to_owned(),
+        subscriber: false,
+    };
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    client.set_session(Session {
+        key: "d580d57f32848f5dcf574d1ce18d78b2".to_owned(),
+        ..renewed.clone()
+    });
+    let session = renewed.clone();
+    client.on_invalid_session(move || {
+        let session = session.clone();
+        async move { Some(session) }
+    });
+
+    assert!(now(client.renew_session()));
+    assert_eq!(client.session(), Some(&renewed));
+}
+
+#[test]
+fn session_hook_without_session() {
+    let session = Session {
+        name: "tzuwy".to_owned(),
+        key: "d580d57f32848f5dcf574d1ce18d78b2".to_owned(),
+        subscriber: false,
+    };
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    assert!(!now(client.renew_session()));
+
+    client.set_session(session.clone());
+    client.on_invalid_session(|| async { None });
+    assert!(!now(client.renew_session()));
+    assert_eq!(client.session(), Some(&session));
+}
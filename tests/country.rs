@@ -0,0 +1,24 @@
+use lastfm_rs::error::Error;
+use lastfm_rs::geo::country::Country;
+
+#[test]
+fn parse_codes() {
+    let country: Country = "KR".parse().unwrap();
+    assert_eq!(country.name(), "Korea, Republic of");
+    assert_eq!("kor".parse::<Country>().unwrap(), country);
+    assert_eq!(country.alpha3(), "KOR");
+}
+
+#[test]
+fn parse_names() {
+    assert_eq!("germany".parse::<Country>().unwrap().alpha2(), "DE");
+    assert_eq!(" United Kingdom ".parse::<Country>().unwrap().alpha2(), "GB");
+    assert_eq!("Russia".parse::<Country>().unwrap().name(), "Russian Federation");
+    assert_eq!("UK".parse::<Country>().unwrap().to_string(), "United Kingdom");
+}
+
+#[test]
+fn parse_unknown() {
+    assert!(matches!("Atlantis".parse::<Country>(), Err(Error::UnknownCountry(ref country)) if country == "Atlantis"));
+    assert!(matches!("XX".parse::<Country>(), Err(Error::UnknownCountry(_))));
+}
@@ -1,8 +1,18 @@
 /// Partially derived from serenity-rs/serenity tests
+use lastfm_rs::album::Endpoints as Album;
+use lastfm_rs::artist::Endpoints as Artist;
+use lastfm_rs::auth::token::Token;
+use lastfm_rs::auth::Endpoints as Auth;
+use lastfm_rs::chart::Endpoints as Chart;
+use lastfm_rs::geo::Endpoints as Geo;
+use lastfm_rs::model::custom_deserialization::tag_list;
+use lastfm_rs::model::IgnoredCode;
+use lastfm_rs::tag::Endpoints as Tag;
+use lastfm_rs::track::Endpoints as Track;
 use lastfm_rs::user::user_info::UserInfo;
 use lastfm_rs::user::User;
 use serde::de::Deserialize;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::fs::File;
 
 macro_rules! p {
@@ -11,7 +21,7 @@ macro_rules! p {
 
         let v = serde_json::from_reader::<File, Value>(f).expect("Loading test file");
 
-        $s::deserialize(v).expect("Deserializing file");
+        $s::deserialize(v).expect("Deserializing file")
     }};
 }
 
@@ -39,3 +49,291 @@ fn user_get_loved_tracks() {
 fn user_get_top_tracks() {
     p!(User, "user.getTopTracks");
 }
+
+#[test]
+fn auth_get_token() {
+    p!(Token, "auth.getToken");
+}
+
+#[test]
+fn auth_get_session() {
+    p!(Auth, "auth.getSession");
+}
+
+#[test]
+fn track_scrobble() {
+    p!(Track, "track.scrobble");
+}
+
+#[test]
+fn track_update_now_playing() {
+    p!(Track, "track.updateNowPlaying");
+}
+
+#[test]
+fn track_scrobble_batch() {
+    let scrobbles = p!(Track, "track.scrobble.batch").scrobbles.unwrap();
+
+    assert_eq!(scrobbles.attrs.accepted, 1);
+    assert_eq!(scrobbles.attrs.ignored, 1);
+    assert!(scrobbles.scrobbles[0].artist.corrected);
+    assert_eq!(scrobbles.scrobbles[0].artist.text, "Guns N' Roses");
+    assert_eq!(scrobbles.scrobbles[1].ignored_message.code, IgnoredCode::TimestampTooOld);
+}
+
+#[test]
+fn track_get_info() {
+    let track = p!(Track, "track.getInfo").track.unwrap();
+
+    assert_eq!(track.duration, Some(240000));
+    assert_eq!(track.playcount, 9987143);
+    assert_eq!(track.user_playcount, Some(21));
+    assert_eq!(track.user_loved, Some(true));
+    assert_eq!(track.tags.len(), 3);
+}
+
+#[test]
+fn track_search() {
+    let search = p!(Track, "track.search").search.unwrap();
+
+    assert_eq!(search.opensearch.total_results, 151731);
+    assert_eq!(search.opensearch.start_index, 0);
+    assert_eq!(search.opensearch.items_per_page, 2);
+    assert_eq!(search.matches.tracks[1].listeners, 1410287);
+}
+
+#[test]
+fn track_get_correction() {
+    let correction = p!(Track, "track.getCorrection").correction.unwrap();
+
+    assert_eq!(correction.track.artist.name, "Guns N' Roses");
+    assert!(correction.attrs.artist_corrected);
+    assert!(!correction.attrs.track_corrected);
+}
+
+#[test]
+fn track_get_correction_without_correction() {
+    let track = serde_json::from_str::<Track>(r#"{"corrections":"\n"}"#).expect("Deserializing correction");
+    assert!(track.correction.is_none());
+}
+
+#[test]
+fn track_get_correction_malformed() {
+    let body = r#"{"corrections":{"correction":{"track":{"name":"Paradise City"},"@attr":{"index":"0"}}}}"#;
+    assert!(serde_json::from_str::<Track>(body).is_err());
+}
+
+#[test]
+fn artist_get_correction() {
+    p!(Artist, "artist.getCorrection");
+}
+
+#[test]
+fn track_get_tags() {
+    p!(Track, "track.getTags");
+}
+
+#[test]
+fn track_get_top_tags() {
+    let top_tags = p!(Track, "track.getTopTags").top_tags.unwrap();
+
+    assert_eq!(top_tags.attrs.track, "Hells Bells");
+    assert_eq!(top_tags.tags[1].count, Some(61));
+}
+
+#[test]
+fn artist_get_tags() {
+    let tags = p!(Artist, "artist.getTags").tags.unwrap();
+
+    assert!(tags.tags.is_empty());
+}
+
+#[test]
+fn artist_get_top_tags() {
+    p!(Artist, "artist.getTopTags");
+}
+
+#[test]
+fn album_get_tags() {
+    let tags = p!(Album, "album.getTags").tags.unwrap();
+
+    assert_eq!(tags.tags.len(), 1);
+    assert_eq!(tags.attrs.album, "Believe");
+}
+
+#[test]
+fn album_get_top_tags() {
+    p!(Album, "album.getTopTags");
+}
+
+#[test]
+fn artist_get_info() {
+    let artist = p!(Artist, "artist.getInfo").artist.unwrap();
+
+    assert!(artist.on_tour);
+    assert_eq!(artist.stats.user_playcount, Some(314));
+    assert_eq!(artist.similar.artists.len(), 2);
+    assert_eq!(artist.bio.unwrap().published.as_deref(), Some("10 Feb 2006, 15:56"));
+}
+
+#[test]
+fn artist_get_similar() {
+    let similar = p!(Artist, "artist.getSimilar").similar_artists.unwrap();
+
+    assert_eq!(similar.artists.len(), 2);
+    assert_eq!(similar.artists[0].r#match, Some(1.0));
+    assert_eq!(similar.artists[1].r#match, Some(0.862463));
+}
+
+#[test]
+fn artist_get_top_tracks() {
+    let top_tracks = p!(Artist, "artist.getTopTracks").top_tracks.unwrap();
+
+    assert_eq!(top_tracks.attrs.artist.as_deref(), Some("Cher"));
+    assert_eq!(top_tracks.attrs.page, "2");
+    assert_eq!(top_tracks.tracks[0].attrs.as_ref().unwrap().rank.as_deref(), Some("3"));
+}
+
+#[test]
+fn artist_get_top_albums() {
+    let top_albums = p!(Artist, "artist.getTopAlbums").top_albums.unwrap();
+
+    assert_eq!(top_albums.attrs.total_pages, "26");
+    assert_eq!(top_albums.albums[0].name, "Believe");
+    assert_eq!(top_albums.albums[0].playcount, Some(9461522));
+    assert_eq!(top_albums.albums[1].attrs.as_ref().unwrap().rank.as_deref(), Some("2"));
+}
+
+#[test]
+fn artist_search() {
+    let search = p!(Artist, "artist.search").search.unwrap();
+
+    assert_eq!(search.opensearch.total_results, 62473);
+    assert_eq!(search.matches.artists.len(), 2);
+    assert_eq!(search.matches.artists[1].listeners, 456329);
+}
+
+#[test]
+fn album_get_info() {
+    let album = p!(Album, "album.getInfo").album.unwrap();
+
+    assert_eq!(album.user_playcount, Some(47));
+    assert_eq!(album.tags.len(), 2);
+
+    let tracks = album.tracks.unwrap().tracks;
+    assert_eq!(tracks.len(), 2);
+    assert_eq!(tracks[0].duration, Some(239));
+    assert_eq!(tracks[1].duration, None);
+    assert_eq!(tracks[1].attrs.as_ref().unwrap().rank, Some(2));
+}
+
+#[test]
+fn album_search() {
+    let search = p!(Album, "album.search").search.unwrap();
+
+    assert_eq!(search.opensearch.total_results, 93845);
+    assert_eq!(search.opensearch.items_per_page, 2);
+    assert_eq!(search.matches.albums[1].artist, "Justin Bieber");
+}
+
+#[test]
+fn tag_get_info() {
+    let tag = p!(Tag, "tag.getInfo").tag.unwrap();
+
+    assert_eq!(tag.total, 301529);
+    assert_eq!(tag.reach, 51839);
+    assert!(tag.wiki.unwrap().published.is_none());
+}
+
+#[test]
+fn tag_get_similar() {
+    p!(Tag, "tag.getSimilar");
+}
+
+#[test]
+fn tag_get_top_tags() {
+    let top_tags = p!(Tag, "tag.getTopTags").top_tags.unwrap();
+
+    assert_eq!(top_tags.attrs.total, 2801);
+    assert_eq!(top_tags.tags[0].count, Some(4024779));
+    assert_eq!(top_tags.tags[1].reach, Some(262484));
+}
+
+#[test]
+fn tag_get_top_artists() {
+    let top_artists = p!(Tag, "tag.getTopArtists").top_artists.unwrap();
+
+    assert_eq!(top_artists.attrs.tag.as_deref(), Some("disco"));
+    assert!(top_artists.attrs.user.is_none());
+    assert_eq!(top_artists.artists[1].attrs.as_ref().unwrap().rank.as_deref(), Some("2"));
+}
+
+#[test]
+fn tag_get_top_albums() {
+    let top_albums = p!(Tag, "tag.getTopAlbums").top_albums.unwrap();
+
+    assert_eq!(top_albums.attrs.tag.as_deref(), Some("disco"));
+    assert_eq!(top_albums.albums[0].artist.as_ref().unwrap().name, "Bee Gees");
+    assert_eq!(top_albums.albums[0].attrs.as_ref().unwrap().rank.as_deref(), Some("1"));
+}
+
+#[test]
+fn tag_get_top_tracks() {
+    let top_tracks = p!(Tag, "tag.getTopTracks").top_tracks.unwrap();
+
+    assert_eq!(top_tracks.attrs.total_pages, "1000");
+    assert_eq!(top_tracks.tracks[1].attrs.as_ref().unwrap().rank.as_deref(), Some("2"));
+}
+
+#[test]
+fn chart_get_top_artists() {
+    let top_artists = p!(Chart, "chart.getTopArtists").top_artists.unwrap();
+
+    assert_eq!(top_artists.attrs.total, "2000");
+    assert_eq!(top_artists.artists[1].listeners, Some(4973529));
+}
+
+#[test]
+fn chart_get_top_tracks() {
+    let top_tracks = p!(Chart, "chart.getTopTracks").top_tracks.unwrap();
+
+    assert_eq!(top_tracks.tracks[0].artist.name, "The Weeknd");
+    assert_eq!(top_tracks.tracks[0].listeners, Some(2389521));
+}
+
+#[test]
+fn chart_get_top_tags() {
+    let top_tags = p!(Chart, "chart.getTopTags").top_tags.unwrap();
+
+    assert_eq!(top_tags.attrs.page, "1");
+    assert_eq!(top_tags.tags[0].reach, Some(397014));
+    assert_eq!(top_tags.tags[0].taggings, Some(4025225));
+}
+
+#[test]
+fn geo_get_top_artists() {
+    let top_artists = p!(Geo, "geo.getTopArtists").top_artists.unwrap();
+
+    assert_eq!(top_artists.attrs.country.as_deref(), Some("Germany"));
+    assert_eq!(top_artists.artists[0].listeners, Some(1327462));
+}
+
+#[test]
+fn geo_get_top_tracks() {
+    let top_tracks = p!(Geo, "geo.getTopTracks").top_tracks.unwrap();
+
+    assert_eq!(top_tracks.attrs.country.as_deref(), Some("Germany"));
+    assert_eq!(top_tracks.tracks[1].attrs.as_ref().unwrap().rank.as_deref(), Some("1"));
+}
+
+#[test]
+fn tag_list_without_tags() {
+    assert!(tag_list(json!("\n")).expect("Deserializing tags").is_empty());
+    assert!(tag_list(json!({})).expect("Deserializing tags").is_empty());
+}
+
+#[test]
+fn tag_list_malformed() {
+    assert!(tag_list(json!({"tag": [{"url": "https://www.last.fm/tag/pop"}]})).is_err());
+    assert!(tag_list(json!(42)).is_err());
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::now;
+use lastfm_rs::error::Error;
+use lastfm_rs::track::scrobble::Scrobble;
+use lastfm_rs::Client;
+
+#[test]
+fn too_many_tags() {
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    let tags = [
+        "pop",
+        "dance",
+        "90s",
+        "female vocalists",
+        "electronic",
+        "disco",
+        "synthpop",
+        "cher",
+        "eurodance",
+        "club",
+        "classic",
+    ];
+
+    let result = now(client.add_track_tags("Cher", "Believe", &tags));
+    assert!(matches!(result, Err(Error::TooManyTags(11))));
+}
+
+#[test]
+fn no_tags() {
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    let result = now(client.add_album_tags("Cher", "Believe", &[]));
+    assert!(matches!(result, Err(Error::NoTags)));
+}
+
+#[test]
+fn too_many_scrobbles() {
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    let scrobbles: Vec<Scrobble> = (0..51).map(|i| Scrobble::new("Cher", "Believe", Utc.timestamp_opt(1287140447 + i * 240, 0).unwrap())).collect();
+
+    let result = now(client.scrobble(&scrobbles));
+    assert!(matches!(result, Err(Error::TooManyScrobbles(51))));
+}
+
+#[test]
+fn no_scrobbles() {
+    let mut client = Client::with_secret("xxxxxxxxxx", "mysecret");
+    let result = now(client.scrobble(&[]));
+    assert!(matches!(result, Err(Error::NoScrobbles)));
+}
@@ -0,0 +1,87 @@
+use chrono::{TimeZone, Utc};
+use lastfm_rs::error::{Error, LastFMError, LastFMErrorResponse};
+use lastfm_rs::scrobbler::queue::ScrobbleQueue;
+use lastfm_rs::track::scrobble::Scrobble;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+fn journal(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("lastfm-rs-{}-{}.jsonl", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn queue_survives_reopening() {
+    let path = journal("queue");
+    let first = Scrobble {
+        album: Some("Takk...".to_owned()),
+        ..Scrobble::new("Sigur Rós", "Hoppípolla", Utc.timestamp_opt(1287140447, 0).unwrap())
+    };
+    let second = Scrobble::new("Vylet Pony", "ANTONYMPH", Utc.timestamp_opt(1287140747, 0).unwrap());
+
+    let mut queue = ScrobbleQueue::open(&path).expect("Opening queue");
+    assert!(queue.is_empty());
+    queue.push(first.clone()).expect("Queueing scrobble");
+    queue.push(second.clone()).expect("Queueing scrobble");
+    drop(queue);
+
+    let queue = ScrobbleQueue::open(&path).expect("Reopening queue");
+    std::fs::remove_file(&path).expect("Removing journal");
+    assert_eq!(queue.pending(), &[first, second]);
+}
+
+#[test]
+fn queue_skips_partially_written_lines() {
+    let path = journal("partial");
+    let scrobble = Scrobble::new("Sigur Rós", "Hoppípolla", Utc.timestamp_opt(1287140447, 0).unwrap());
+
+    let mut queue = ScrobbleQueue::open(&path).expect("Opening queue");
+    queue.push(scrobble.clone()).expect("Queueing scrobble");
+    let mut file = OpenOptions::new().append(true).open(&path).expect("Opening journal");
+    file.write_all(br#"{"artist":"Vylet Pony","tra"#).expect("Writing journal");
+
+    let mut queue = ScrobbleQueue::open(&path).expect("Reopening queue");
+    assert_eq!(queue.len(), 1);
+    queue.push(scrobble.clone()).expect("Queueing scrobble");
+
+    let queue = ScrobbleQueue::open(&path).expect("Reopening queue");
+    std::fs::remove_file(&path).expect("Removing journal");
+    assert_eq!(queue.pending(), &[scrobble.clone(), scrobble]);
+}
+
+#[test]
+fn queue_discard() {
+    let path = journal("discard");
+    let scrobbles: Vec<Scrobble> = (0..3)
+        .map(|i| Scrobble::new("Cher", &format!("Track {}", i), Utc.timestamp_opt(1287140447 + i * 240, 0).unwrap()))
+        .collect();
+
+    let mut queue = ScrobbleQueue::open(&path).expect("Opening queue");
+    for scrobble in &scrobbles {
+        queue.push(scrobble.clone()).expect("Queueing scrobble");
+    }
+    assert_eq!(queue.discard(2).expect("Discarding scrobbles"), scrobbles[..2].to_vec());
+    assert_eq!(queue.discard(5).expect("Discarding scrobbles"), scrobbles[2..].to_vec());
+
+    let queue = ScrobbleQueue::open(&path).expect("Reopening queue");
+    std::fs::remove_file(&path).expect("Removing journal");
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn transient_errors() {
+    let error = |code: i32| {
+        let lastfm_error = LastFMError {
+            error: code,
+            message: String::new(),
+            links: None,
+        };
+        Error::LastFMError(LastFMErrorResponse::from(lastfm_error))
+    };
+
+    assert!(error(8).is_transient());
+    assert!(error(11).is_transient());
+    assert!(!error(6).is_transient());
+    assert!(!error(9).is_transient());
+}
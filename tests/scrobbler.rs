@@ -0,0 +1,129 @@
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use lastfm_rs::scrobbler::player::{Clock, PlayerEvent, Scrobbler, ScrobblerAction, TrackMetadata};
+use std::cell::Cell;
+use std::time::Duration;
+
+struct FakeClock(Cell<DateTime<Utc>>);
+
+impl FakeClock {
+    fn new() -> FakeClock {
+        FakeClock(Cell::new(Utc.timestamp_opt(1287140447, 0).unwrap()))
+    }
+
+    fn advance(&self, seconds: i64) {
+        self.0.set(self.0.get() + ChronoDuration::seconds(seconds));
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0.get()
+    }
+}
+
+fn track(seconds: u64) -> TrackMetadata {
+    TrackMetadata {
+        artist: "Sigur Rós".to_owned(),
+        track: "Hoppípolla".to_owned(),
+        album: Some("Takk...".to_owned()),
+        album_artist: None,
+        track_number: Some(2),
+        mbid: None,
+        duration: Duration::from_secs(seconds),
+    }
+}
+
+fn scrobbles(actions: &[ScrobblerAction]) -> Vec<i64> {
+    actions
+        .iter()
+        .filter_map(|action| match action {
+            ScrobblerAction::Scrobble(scrobble) => Some(scrobble.timestamp.timestamp()),
+            ScrobblerAction::NowPlaying(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn now_playing_on_start() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    assert_eq!(scrobbler.handle(PlayerEvent::Started(track(268))), vec![ScrobblerAction::NowPlaying(track(268))]);
+}
+
+#[test]
+fn scrobble_after_half_duration() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(268)));
+
+    clock.advance(133);
+    assert_eq!(scrobbler.poll(), None);
+    clock.advance(1);
+    assert!(matches!(scrobbler.poll(), Some(ScrobblerAction::Scrobble(ref scrobble)) if scrobble.timestamp.timestamp() == 1287140447));
+    assert_eq!(scrobbler.poll(), None);
+    assert!(scrobbles(&scrobbler.handle(PlayerEvent::Stopped)).is_empty());
+}
+
+#[test]
+fn scrobble_after_four_minutes() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(1200)));
+
+    clock.advance(240);
+    assert_eq!(scrobbles(&scrobbler.handle(PlayerEvent::Stopped)), vec![1287140447]);
+}
+
+#[test]
+fn short_tracks_are_not_scrobbled() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(30)));
+
+    clock.advance(30);
+    assert!(scrobbles(&scrobbler.handle(PlayerEvent::Stopped)).is_empty());
+}
+
+#[test]
+fn pauses_do_not_count() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(200)));
+
+    clock.advance(60);
+    scrobbler.handle(PlayerEvent::Paused);
+    clock.advance(600);
+    assert_eq!(scrobbler.poll(), None);
+    scrobbler.handle(PlayerEvent::Resumed);
+    clock.advance(39);
+    assert_eq!(scrobbler.poll(), None);
+    clock.advance(1);
+    assert!(scrobbler.poll().is_some());
+}
+
+#[test]
+fn seeking_does_not_count() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(200)));
+
+    clock.advance(10);
+    scrobbler.handle(PlayerEvent::Seeked(Duration::from_secs(190)));
+    clock.advance(10);
+    assert!(scrobbles(&scrobbler.handle(PlayerEvent::Stopped)).is_empty());
+}
+
+#[test]
+fn starting_another_track_scrobbles_the_previous_one() {
+    let clock = FakeClock::new();
+    let mut scrobbler = Scrobbler::with_clock(&clock);
+    scrobbler.handle(PlayerEvent::Started(track(200)));
+
+    clock.advance(150);
+    let actions = scrobbler.handle(PlayerEvent::Started(track(300)));
+    assert_eq!(scrobbles(&actions), vec![1287140447]);
+    assert_eq!(actions.last(), Some(&ScrobblerAction::NowPlaying(track(300))));
+
+    clock.advance(150);
+    assert_eq!(scrobbles(&scrobbler.handle(PlayerEvent::Stopped)), vec![1287140597]);
+}
@@ -0,0 +1,37 @@
+use lastfm_rs::utilities::sign;
+
+fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+#[test]
+fn sign_sorts_parameters() {
+    let params = params(&[("token", "yyyyyyyyyy"), ("method", "auth.getSession"), ("api_key", "xxxxxxxxxx")]);
+    assert_eq!(sign(&params, "mysecret"), "b8f15cdadc94c10cd7f8073ff9bfb7fd");
+}
+
+#[test]
+fn sign_excludes_format_and_callback() {
+    let plain = params(&[("api_key", "xxxxxxxxxx"), ("method", "auth.getSession"), ("token", "yyyyyyyyyy")]);
+    let extra = params(&[
+        ("api_key", "xxxxxxxxxx"),
+        ("format", "json"),
+        ("callback", "cb"),
+        ("method", "auth.getSession"),
+        ("token", "yyyyyyyyyy"),
+    ]);
+    assert_eq!(sign(&plain, "mysecret"), sign(&extra, "mysecret"));
+}
+
+#[test]
+fn sign_indexed_utf8_parameters() {
+    let params = params(&[
+        ("method", "track.scrobble"),
+        ("api_key", "xxxxxxxxxx"),
+        ("sk", "0123456789"),
+        ("artist[0]", "Sigur Rós"),
+        ("track[0]", "Hoppípolla"),
+        ("timestamp[0]", "1287140447"),
+    ]);
+    assert_eq!(sign(&params, "mysecret"), "6236757e8c6a038ab189e894dbaf558c");
+}